@@ -0,0 +1,54 @@
+use serde::Deserialize;
+
+use crate::data::{ReleaseInfo, parse_loose_version, upstream_version};
+
+/// Response structure for the AUR RPC `info` request.
+#[derive(Deserialize)]
+struct AurResponse {
+    #[serde(default)]
+    results: Vec<AurPackage>,
+    error: Option<String>,
+}
+
+/// A package in an AUR RPC response.
+#[derive(Deserialize)]
+struct AurPackage {
+    #[serde(rename = "Name")]
+    name: String,
+    #[serde(rename = "Version")]
+    version: String,
+}
+
+impl ReleaseInfo {
+    /// Creates a `ReleaseInfo` from an AUR RPC `info` response.
+    ///
+    /// The `pkgver` of the package becomes the latest version; the epoch and
+    /// `pkgrel` are ignored, so it can be compared with upstream versions.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The response of `https://aur.archlinux.org/rpc/?v=5&type=info&arg[]=<name>`
+    /// * `name` - The package name
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the response cannot be parsed, reports an error or
+    /// does not contain the package.
+    pub(crate) fn from_aur(json: &str, name: &str) -> anyhow::Result<Self> {
+        let response: AurResponse = serde_json::from_str(json)
+            .map_err(|e| anyhow::anyhow!("Failed to parse AUR response: {e}"))?;
+        if let Some(error) = response.error {
+            anyhow::bail!("The AUR returned an error: {error}");
+        }
+        let package = response
+            .results
+            .into_iter()
+            .find(|package| package.name == name)
+            .ok_or_else(|| anyhow::anyhow!("{name} was not found in the AUR"))?;
+        let version = upstream_version(&package.version);
+        let version = parse_loose_version(version)
+            .ok_or_else(|| anyhow::anyhow!("Failed to parse latest version: '{version}'"))?;
+        let url = format!("https://aur.archlinux.org/packages/{name}");
+        Ok(Self::new(version, url))
+    }
+}
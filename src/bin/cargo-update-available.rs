@@ -0,0 +1,6 @@
+//! The `cargo update-available` subcommand.
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    update_available::cli::run_cargo()
+}
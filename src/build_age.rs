@@ -0,0 +1,151 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+use core::time::Duration;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Serializer;
+
+use crate::data::UpdateInfo;
+
+/// The number of seconds in a day.
+const DAY: u64 = 24 * 60 * 60;
+
+impl UpdateInfo {
+    /// Returns whether a build is older than `max_age` and a newer release
+    /// exists.
+    ///
+    /// A release counts as newer if its version is newer, or if it was
+    /// published after the build. This catches nightly and rolling builds
+    /// whose version numbers do not change with every release.
+    ///
+    /// # Arguments
+    ///
+    /// * `built_at` - When the running binary was built, see [`crate::parse_build_time`]
+    /// * `max_age` - How old a build may get before it is considered stale
+    #[must_use]
+    pub fn is_stale_build(&self, built_at: SystemTime, max_age: Duration) -> bool {
+        SystemTime::now()
+            .duration_since(built_at)
+            .is_ok_and(|age| age > max_age)
+            && (self.is_update_available
+                || self
+                    .published_at
+                    .is_some_and(|published| published > built_at))
+    }
+
+    /// Returns a notice like "Your build of my-tool is 45 days old and a
+    /// newer release exists" if the build is stale, see
+    /// [`UpdateInfo::is_stale_build`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use update_available::{check_crates_io, parse_build_time};
+    ///
+    /// // E.g. set by a build script from `SOURCE_DATE_EPOCH`
+    /// let built_at = parse_build_time("1700000000").unwrap();
+    /// if let Ok(info) = check_crates_io("serde", "1.0.0")
+    ///     && let Some(notice) = info.stale_build_notice(built_at, Duration::from_hours(30 * 24))
+    /// {
+    ///     eprintln!("{notice}");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn stale_build_notice(&self, built_at: SystemTime, max_age: Duration) -> Option<String> {
+        if !self.is_stale_build(built_at, max_age) {
+            return None;
+        }
+        let days = SystemTime::now()
+            .duration_since(built_at)
+            .map_or(0, |age| age.as_secs() / DAY);
+        Some(format!(
+            "Your build of {} is {days} days old and a newer release exists: {}",
+            self.name, self.url
+        ))
+    }
+}
+
+/// Parses the build time of a binary, e.g. embedded at compile time with
+/// `env!` by a build script.
+///
+/// Accepts seconds since the Unix epoch, as in `SOURCE_DATE_EPOCH`, or an
+/// RFC 3339 timestamp like `2024-05-01T12:00:00Z`.
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::parse_build_time;
+///
+/// assert_eq!(parse_build_time("1714564800"), parse_build_time("2024-05-01T12:00:00Z"));
+/// ```
+#[must_use]
+pub fn parse_build_time(value: &str) -> Option<SystemTime> {
+    let value = value.trim();
+    value.parse::<u64>().map_or_else(
+        |_| parse_rfc3339(value),
+        |seconds| Some(UNIX_EPOCH + Duration::from_secs(seconds)),
+    )
+}
+
+/// Parses an RFC 3339 timestamp like `2024-05-01T12:00:00.123+02:00`.
+///
+/// Fractions of seconds are ignored.
+pub(crate) fn parse_rfc3339(value: &str) -> Option<SystemTime> {
+    let (date, time) = value.split_once(['T', 't', ' '])?;
+    let mut date = date.splitn(3, '-').map(str::parse::<u64>);
+    let (year, month, day) = (date.next()?.ok()?, date.next()?.ok()?, date.next()?.ok()?);
+    let (time, offset) = time.split_at(time.find(['Z', 'z', '+', '-'])?);
+    let mut time = time.splitn(3, ':');
+    let hour: u64 = time.next()?.parse().ok()?;
+    let minute: u64 = time.next()?.parse().ok()?;
+    let second: u64 = time.next()?.split('.').next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 {
+        return None;
+    }
+    let local = days_since_epoch(year, month, day)? * DAY + hour * 3600 + minute * 60 + second;
+    let utc = match offset.as_bytes().first() {
+        Some(b'Z' | b'z') => Some(local),
+        Some(sign @ (b'+' | b'-')) => {
+            let (hours, minutes) = offset.get(1..)?.split_once(':')?;
+            let offset = hours.parse::<u64>().ok()? * 3600 + minutes.parse::<u64>().ok()? * 60;
+            // East of UTC is ahead of it.
+            if *sign == b'+' {
+                local.checked_sub(offset)
+            } else {
+                local.checked_add(offset)
+            }
+        }
+        _ => None,
+    }?;
+    Some(UNIX_EPOCH + Duration::from_secs(utc))
+}
+
+/// Returns the number of days from 1970-01-01 to a date, or `None` for dates
+/// before the epoch.
+///
+/// Uses the algorithm of Howard Hinnant's `days_from_civil`.
+fn days_since_epoch(year: u64, month: u64, day: u64) -> Option<u64> {
+    let year = if month <= 2 {
+        year.checked_sub(1)?
+    } else {
+        year
+    };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    (era * 146_097 + day_of_era).checked_sub(719_468)
+}
+
+/// Serializes an optional time as seconds since the Unix epoch.
+#[expect(clippy::ref_option, reason = "serde passes a reference to the field")]
+pub(crate) fn serialize_unix_seconds<S: Serializer>(
+    time: &Option<SystemTime>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match time.and_then(|time| time.duration_since(UNIX_EPOCH).ok()) {
+        Some(duration) => serializer.serialize_some(&duration.as_secs()),
+        None => serializer.serialize_none(),
+    }
+}
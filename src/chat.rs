@@ -0,0 +1,118 @@
+use serde_json::{Value, json};
+
+use crate::data::{UpdateInfo, UpdateKind};
+
+/// The maximum length of a Slack section text.
+const SLACK_TEXT_LIMIT: usize = 3000;
+/// The maximum length of a Discord embed description.
+const DISCORD_DESCRIPTION_LIMIT: usize = 4096;
+
+impl UpdateInfo {
+    /// Formats the update as a Slack message with [Block Kit](https://api.slack.com/block-kit) blocks.
+    ///
+    /// The message has a header, the current and latest version, the
+    /// changelog if there is one and a link for more information. It can be
+    /// posted to a Slack incoming webhook as is, e.g. with a `Webhook` using
+    /// `WebhookFormat::Slack` (`webhook` feature).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::check_crates_io;
+    ///
+    /// if let Ok(info) = check_crates_io("serde", "1.0.0") {
+    ///     println!("{}", info.to_slack_message());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn to_slack_message(&self) -> Value {
+        let mut blocks = vec![
+            json!({
+                "type": "header",
+                "text": {"type": "plain_text", "text": self.chat_title()},
+            }),
+            json!({
+                "type": "section",
+                "fields": [
+                    {"type": "mrkdwn", "text": format!("*Current version:*\n{}", self.current_version)},
+                    {"type": "mrkdwn", "text": format!("*Latest version:*\n{}", self.latest_version)},
+                ],
+            }),
+        ];
+        if let Some(changelog) = self.changelog.as_deref().filter(|c| !c.trim().is_empty()) {
+            blocks.push(json!({
+                "type": "section",
+                "text": {"type": "mrkdwn", "text": truncate(changelog, SLACK_TEXT_LIMIT)},
+            }));
+        }
+        blocks.push(json!({
+            "type": "context",
+            "elements": [{"type": "mrkdwn", "text": format!("<{}|More info>", self.url)}],
+        }));
+        json!({"text": self.chat_title(), "blocks": blocks})
+    }
+
+    /// Formats the update as a Discord message with an [embed](https://discord.com/developers/docs/resources/message#embed-object).
+    ///
+    /// The embed links to the release, shows the changelog if there is one
+    /// and is colored by the [`UpdateKind`]. It can be posted to a Discord
+    /// webhook as is, e.g. with a `Webhook` using `WebhookFormat::Discord`
+    /// (`webhook` feature).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::check_crates_io;
+    ///
+    /// if let Ok(info) = check_crates_io("serde", "1.0.0") {
+    ///     println!("{}", info.to_discord_message());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn to_discord_message(&self) -> Value {
+        let mut embed = json!({
+            "title": self.chat_title(),
+            "url": self.url,
+            "color": discord_color(self.update_kind),
+            "fields": [
+                {"name": "Current version", "value": self.current_version.to_string(), "inline": true},
+                {"name": "Latest version", "value": self.latest_version.to_string(), "inline": true},
+            ],
+        });
+        if let Some(changelog) = self.changelog.as_deref().filter(|c| !c.trim().is_empty()) {
+            embed["description"] = Value::from(truncate(changelog, DISCORD_DESCRIPTION_LIMIT));
+        }
+        json!({"embeds": [embed]})
+    }
+
+    /// Returns the headline of chat messages.
+    fn chat_title(&self) -> String {
+        if self.is_update_available {
+            format!(
+                "New version of {} released: {}",
+                self.name, self.latest_version
+            )
+        } else {
+            format!("{} {} is up to date", self.name, self.current_version)
+        }
+    }
+}
+
+/// Returns the embed color for an update of the given kind.
+const fn discord_color(kind: Option<UpdateKind>) -> u32 {
+    match kind {
+        Some(UpdateKind::Major) => 0x00E7_4C3C,
+        Some(UpdateKind::Minor) => 0x00F1_C40F,
+        Some(UpdateKind::Patch) => 0x002E_CC71,
+        Some(UpdateKind::Prerelease) => 0x0095_A5A6,
+        None => 0x0034_98DB,
+    }
+}
+
+/// Shortens `text` to at most `limit` characters, ending it with `…` if cut.
+fn truncate(text: &str, limit: usize) -> String {
+    match text.char_indices().nth(limit.saturating_sub(1)) {
+        Some((end, _)) if text.chars().count() > limit => format!("{}…", &text[..end]),
+        _ => text.to_owned(),
+    }
+}
@@ -0,0 +1,636 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+use core::time::Duration;
+use std::net::{TcpStream, ToSocketAddrs as _};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::{InstallMethod, Source, data::UpdateInfo};
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
+#[cfg(feature = "blocking")]
+use std::time::Instant;
+
+#[cfg(feature = "blocking")]
+use crate::{UpdateAvailable, UpdateSource as _};
+
+/// The environment variable users set to opt out of all update checks.
+pub const NO_UPDATE_CHECK: &str = "NO_UPDATE_CHECK";
+
+/// Whether all update checks were turned off with [`disable`].
+static DISABLED: AtomicBool = AtomicBool::new(false);
+
+/// Turns off all update checks of this process.
+///
+/// Every [`Checker`] returns [`SkipReason::Disabled`], the `print_check`
+/// functions print nothing, and every other check fails without sending a
+/// request, regardless of where the checks are embedded. Use it for
+/// enterprise builds or an `--offline` mode.
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::{CheckOutcome, Checker, SkipReason, Source};
+///
+/// update_available::disable();
+/// let outcome = Checker::new("serde", "1.0.0", Source::CratesIo).check().unwrap();
+/// assert!(matches!(outcome, CheckOutcome::Skipped(SkipReason::Disabled)));
+/// assert!(update_available::check_crates_io("serde", "1.0.0").is_err());
+/// # update_available::enable();
+/// ```
+pub fn disable() {
+    DISABLED.store(true, Ordering::Relaxed);
+}
+
+/// Turns update checks back on after [`disable`].
+pub fn enable() {
+    DISABLED.store(false, Ordering::Relaxed);
+}
+
+/// Returns whether all update checks were turned off with [`disable`] or the
+/// `UPDATE_AVAILABLE_DISABLE` environment variable.
+#[must_use]
+pub fn is_disabled() -> bool {
+    DISABLED.load(Ordering::Relaxed)
+        || std::env::var(crate::config::DISABLE_ENV).is_ok_and(|value| is_enabled(&value))
+}
+
+/// The reason an update check was skipped without contacting the source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SkipReason {
+    /// All update checks were turned off with [`disable`].
+    Disabled,
+    /// The user opted out with the given environment variable.
+    OptedOut(String),
+    /// The check runs in a CI environment (`CI` is set).
+    Ci,
+}
+
+/// The outcome of a [`Checker`] run.
+#[derive(Clone)]
+#[expect(
+    clippy::large_enum_variant,
+    reason = "An outcome is created once per check, so boxing would only complicate matching"
+)]
+pub enum CheckOutcome {
+    /// The source was checked.
+    Checked(UpdateInfo),
+    /// The check was skipped, for example because the user opted out.
+    Skipped(SkipReason),
+    /// The connectivity probe failed, so the source was not checked.
+    ///
+    /// Only returned if [`Checker::offline_probe`] is enabled.
+    Offline,
+}
+
+impl CheckOutcome {
+    /// Returns the update information if the source was checked.
+    #[must_use]
+    pub const fn update_info(&self) -> Option<&UpdateInfo> {
+        match self {
+            Self::Checked(info) => Some(info),
+            Self::Skipped(_) | Self::Offline => None,
+        }
+    }
+
+    /// Returns whether the source was checked and an update is available.
+    #[must_use]
+    pub fn is_update_available(&self) -> bool {
+        self.update_info()
+            .is_some_and(|info| info.is_update_available)
+    }
+}
+
+/// Metrics of a check, passed to the callback set with
+/// [`Checker::on_metrics`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CheckMetrics {
+    /// The kind of source checked, e.g. `crates-io` or `github`.
+    pub source: &'static str,
+    /// The time the check took.
+    pub duration: Duration,
+    /// The HTTP status of the last response, if one was received.
+    pub status: Option<u16>,
+    /// The number of response body bytes read.
+    pub bytes: u64,
+    /// Whether the last response was served by an HTTP cache, such as a CDN,
+    /// according to its `X-Cache` header.
+    pub cache_hit: bool,
+}
+
+/// A callback receiving the metrics of a check.
+#[cfg(feature = "blocking")]
+type MetricsCallback = dyn Fn(&CheckMetrics) + Send + Sync;
+
+/// Checks a package for updates while honoring the user's preferences.
+///
+/// Unlike the `check_*` functions, a `Checker` skips the check if the user
+/// opted out via [`NO_UPDATE_CHECK`] or a tool-specific environment variable,
+/// and optionally in CI environments, so tools embedding the checker do not
+/// have to re-implement this etiquette.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use update_available::{CheckOutcome, Checker, Source};
+///
+/// let checker = Checker::new("serde", "1.0.0", Source::CratesIo)
+///     .opt_out_env("SERDE_NO_UPDATE_CHECK")
+///     .skip_in_ci(true)
+///     .offline_probe(Duration::from_millis(300));
+/// match checker.check() {
+///     Ok(CheckOutcome::Checked(info)) => info.print(),
+///     Ok(CheckOutcome::Skipped(reason)) => eprintln!("Update check skipped: {reason:?}"),
+///     Ok(CheckOutcome::Offline) => eprintln!("Offline, skipping the update check"),
+///     Err(e) => eprintln!("Error checking for updates: {e}"),
+/// }
+/// ```
+pub struct Checker {
+    name: String,
+    current_version: String,
+    source: Source,
+    opt_out_env: Option<String>,
+    skip_in_ci: bool,
+    offline_probe: Option<Duration>,
+    install_method: Option<InstallMethod>,
+    asset_pattern: Option<String>,
+    highest_version: bool,
+    user_agent: Option<String>,
+    history: Option<PathBuf>,
+    #[cfg(feature = "blocking")]
+    before_request: Vec<Arc<crate::http::BeforeRequest>>,
+    #[cfg(feature = "blocking")]
+    after_response: Vec<Arc<crate::http::AfterResponse>>,
+    #[cfg(feature = "blocking")]
+    on_metrics: Option<Arc<MetricsCallback>>,
+    #[cfg(feature = "blocking")]
+    client: Option<crate::UpdateClient>,
+    #[cfg(feature = "github-app")]
+    github_app: Option<crate::GithubApp>,
+}
+
+impl Checker {
+    /// Creates a checker for a package on the given source.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the package to check
+    /// * `current_version` - The current version string (e.g., "1.0.0")
+    /// * `source` - The source to check for updates
+    #[must_use]
+    pub fn new(name: &str, current_version: &str, source: Source) -> Self {
+        Self {
+            name: name.to_owned(),
+            current_version: current_version.to_owned(),
+            source,
+            opt_out_env: None,
+            skip_in_ci: false,
+            offline_probe: None,
+            install_method: None,
+            asset_pattern: None,
+            highest_version: false,
+            user_agent: None,
+            history: None,
+            #[cfg(feature = "blocking")]
+            before_request: Vec::new(),
+            #[cfg(feature = "blocking")]
+            after_response: Vec::new(),
+            #[cfg(feature = "blocking")]
+            on_metrics: None,
+            #[cfg(feature = "blocking")]
+            client: None,
+            #[cfg(feature = "github-app")]
+            github_app: None,
+        }
+    }
+
+    /// Skips the check if the given environment variable is set, in addition
+    /// to [`NO_UPDATE_CHECK`].
+    ///
+    /// Use a tool-specific name like `MYTOOL_NO_UPDATE_CHECK`.
+    #[must_use]
+    pub fn opt_out_env(mut self, variable: &str) -> Self {
+        self.opt_out_env = Some(variable.to_owned());
+        self
+    }
+
+    /// Skips the check if the `CI` environment variable is set.
+    ///
+    /// Defaults to `false`.
+    #[must_use]
+    pub const fn skip_in_ci(mut self, skip: bool) -> Self {
+        self.skip_in_ci = skip;
+        self
+    }
+
+    /// Probes the source's host before checking, returning
+    /// [`CheckOutcome::Offline`] if it cannot be reached within `timeout`.
+    ///
+    /// Without a network connection, a regular check only fails after the
+    /// full TCP timeout, which delays the startup of CLIs noticeably. The probe
+    /// opens a TCP connection with a short timeout (e.g. 300 ms) instead.
+    /// File and custom sources are never probed.
+    #[must_use]
+    pub const fn offline_probe(mut self, timeout: Duration) -> Self {
+        self.offline_probe = Some(timeout);
+        self
+    }
+
+    /// Tailors the suggested upgrade command to how the binary was installed.
+    ///
+    /// The command reported by the source (e.g. `cargo install` for
+    /// crates.io) is replaced by the one of the install method, or removed for
+    /// [`InstallMethod::System`]. [`InstallMethod::Unknown`] keeps the
+    /// source's suggestion.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::{Checker, InstallMethod, Source};
+    ///
+    /// let checker = Checker::new("my-tool", "1.0.0", Source::CratesIo)
+    ///     .install_method(InstallMethod::detect());
+    /// ```
+    #[must_use]
+    pub const fn install_method(mut self, method: InstallMethod) -> Self {
+        self.install_method = Some(method);
+        self
+    }
+
+    /// Only reports releases that have an asset matching a glob pattern.
+    ///
+    /// Releases without a matching asset are ignored, so a release without a
+    /// build for the user's platform is not announced to users who cannot
+    /// install it yet. In the pattern, `*` matches any run of characters and
+    /// `?` a single character; case is ignored. Drafts and pre-releases are
+    /// never reported.
+    ///
+    /// Only supported for [`Source::Github`] and [`Source::Gitea`]; checking
+    /// other sources fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::{Checker, Source};
+    ///
+    /// let checker = Checker::new("my-tool", "1.0.0", Source::Github("user".to_string()))
+    ///     .require_asset_matching("*x86_64-pc-windows-msvc*.zip");
+    /// ```
+    #[must_use]
+    pub fn require_asset_matching(mut self, pattern: &str) -> Self {
+        self.asset_pattern = Some(pattern.to_owned());
+        self
+    }
+
+    /// Reports the release with the highest version instead of the one
+    /// published last.
+    ///
+    /// The latest release of GitHub and Gitea is the one published last, so
+    /// a patch for an older major version can hide a newer release. With this
+    /// set, all pages of the releases list are fetched and the highest stable
+    /// version is reported. Drafts are never reported; pre-releases are
+    /// considered if the current version is a pre-release or there is no
+    /// stable release, and offered as with any other source.
+    ///
+    /// Only supported for [`Source::Github`] and [`Source::Gitea`]; checking
+    /// other sources fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::{Checker, Source};
+    ///
+    /// let source = Source::Gitea("user".to_string(), "https://gitea.example.com".to_string());
+    /// let checker = Checker::new("my-tool", "1.0.0", source).highest_version();
+    /// ```
+    #[must_use]
+    pub const fn highest_version(mut self) -> Self {
+        self.highest_version = true;
+        self
+    }
+
+    /// Identifies the application in the `User-Agent` of every request.
+    ///
+    /// The library is appended as a comment, e.g. `my-tool/1.2.0` is sent
+    /// as `my-tool/1.2.0 (update-available/0.1.2)`. GitHub asks clients to
+    /// identify themselves this way; by default, `update-available-lib` is
+    /// sent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::{Checker, Source};
+    ///
+    /// let checker = Checker::new("my-tool", "1.2.0", Source::CratesIo)
+    ///     .user_agent(concat!("my-tool/", env!("CARGO_PKG_VERSION")));
+    /// ```
+    #[must_use]
+    pub fn user_agent(mut self, product: &str) -> Self {
+        self.user_agent = Some(product.to_owned());
+        self
+    }
+
+    /// Records the latest version of every successful check in an
+    /// [`UpdateHistory`](crate::UpdateHistory) file.
+    ///
+    /// The history is only written on a best-effort basis: failing to read
+    /// or write it does not fail the check.
+    #[must_use]
+    pub fn record_history(mut self, path: impl Into<PathBuf>) -> Self {
+        self.history = Some(path.into());
+        self
+    }
+
+    /// Calls a hook with every request before it is sent.
+    ///
+    /// The hook receives the method, URI and headers of the request, after
+    /// the `User-Agent` and extra headers are set, and may change them, e.g.
+    /// to sign the request or log it. Returning an error fails the request.
+    /// Hooks are called in the order they were added.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::{Checker, Source};
+    ///
+    /// let checker = Checker::new("my-tool", "1.0.0", Source::CratesIo).before_request(|request| {
+    ///     eprintln!("{} {}", request.method, request.uri);
+    ///     request.headers.insert("X-Request-Signature", "signature".parse()?);
+    ///     Ok(())
+    /// });
+    /// ```
+    #[cfg(feature = "blocking")]
+    #[must_use]
+    pub fn before_request(
+        mut self,
+        hook: impl Fn(&mut ureq::http::request::Parts) -> anyhow::Result<()> + Send + Sync + 'static,
+    ) -> Self {
+        self.before_request.push(Arc::new(hook));
+        self
+    }
+
+    /// Calls a hook with the metadata of every response.
+    ///
+    /// The hook receives the status, version and headers of the response,
+    /// e.g. to log them or to validate the response. Returning an error fails
+    /// the request. Hooks are called in the order they were added.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::{Checker, Source};
+    ///
+    /// let checker = Checker::new("my-tool", "1.0.0", Source::CratesIo).after_response(|response| {
+    ///     if !response.headers.contains_key("X-Served-By") {
+    ///         anyhow::bail!("The response did not pass the gateway");
+    ///     }
+    ///     Ok(())
+    /// });
+    /// ```
+    #[cfg(feature = "blocking")]
+    #[must_use]
+    pub fn after_response(
+        mut self,
+        hook: impl Fn(&ureq::http::response::Parts) -> anyhow::Result<()> + Send + Sync + 'static,
+    ) -> Self {
+        self.after_response.push(Arc::new(hook));
+        self
+    }
+
+    /// Calls a callback with the metrics of every check that contacts the
+    /// source, whether it succeeds or fails.
+    ///
+    /// Long-running services can use it to export update-check metrics, e.g.
+    /// to Prometheus. Skipped checks and failed offline probes are not
+    /// reported.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::{Checker, Source};
+    ///
+    /// let checker = Checker::new("my-tool", "1.0.0", Source::CratesIo).on_metrics(|metrics| {
+    ///     eprintln!(
+    ///         "{}: {:?} in {:?}, {} bytes",
+    ///         metrics.source, metrics.status, metrics.duration, metrics.bytes
+    ///     );
+    /// });
+    /// ```
+    #[cfg(feature = "blocking")]
+    #[must_use]
+    pub fn on_metrics(mut self, callback: impl Fn(&CheckMetrics) + Send + Sync + 'static) -> Self {
+        self.on_metrics = Some(Arc::new(callback));
+        self
+    }
+
+    /// Sends the requests of the check within the request budgets of a
+    /// client, shared with its other checks, and with its host overrides.
+    ///
+    /// See [`crate::UpdateClient::rate_limit`] and
+    /// [`crate::UpdateClient::resolve`].
+    #[cfg(feature = "blocking")]
+    #[must_use]
+    pub fn client(mut self, client: &crate::UpdateClient) -> Self {
+        self.client = Some(client.clone());
+        self
+    }
+
+    /// Authenticates requests to GitHub as an installation of a GitHub App.
+    ///
+    /// An installation token is requested before the check if the cached
+    /// one is about to expire, and replaces `UPDATE_AVAILABLE_GITHUB_TOKEN`.
+    /// Checks of other sources are not authenticated. See
+    /// [`crate::GithubApp`].
+    #[cfg(feature = "github-app")]
+    #[must_use]
+    pub fn github_app(mut self, app: &crate::GithubApp) -> Self {
+        self.github_app = Some(app.clone());
+        self
+    }
+
+    /// Sends the token stored for the source in the platform keychain, if
+    /// there is one.
+    ///
+    /// The token is read once, when this is called; if the keychain cannot
+    /// be accessed, e.g. on a headless server, the check runs without it.
+    /// `UPDATE_AVAILABLE_GITHUB_TOKEN` and `UPDATE_AVAILABLE_GITEA_TOKEN`
+    /// take precedence. See [`crate::TokenStore`].
+    #[cfg(feature = "keyring")]
+    #[must_use]
+    pub fn keyring_token(self, store: &crate::TokenStore) -> Self {
+        match store.load(&self.source) {
+            Ok(Some(token)) => Self {
+                source: self
+                    .source
+                    .with_header("Authorization", &format!("Bearer {token}")),
+                ..self
+            },
+            Ok(None) | Err(_) => self,
+        }
+    }
+
+    /// Returns why the check would be skipped, or `None` if it would run.
+    #[must_use]
+    pub fn skip_reason(&self) -> Option<SkipReason> {
+        if is_disabled() {
+            return Some(SkipReason::Disabled);
+        }
+        skip_reason(self.opt_out_env.as_deref(), self.skip_in_ci, |variable| {
+            std::env::var(variable).ok()
+        })
+    }
+
+    /// Checks the source for updates, unless the check is skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source cannot be checked.
+    #[cfg(feature = "blocking")]
+    pub fn check(&self) -> anyhow::Result<CheckOutcome> {
+        if let Some(reason) = self.skip_reason() {
+            return Ok(CheckOutcome::Skipped(reason));
+        }
+        if let (Some(timeout), Some(address)) = (self.offline_probe, self.source.probe_address())
+            && !is_reachable(&address, timeout)
+        {
+            return Ok(CheckOutcome::Offline);
+        }
+        let stats = self
+            .on_metrics
+            .as_ref()
+            .map(|_| Arc::new(crate::http::RequestStats::default()));
+        let mut config = crate::http::RequestConfig {
+            user_agent: self.user_agent.clone(),
+            headers: crate::config::env_authorization(&self.source, crate::config::env_var)
+                .into_iter()
+                .collect(),
+            before_request: self.before_request.clone(),
+            after_response: self.after_response.clone(),
+            stats: stats.clone(),
+            ..crate::http::RequestConfig::default()
+        };
+        #[cfg(feature = "github-app")]
+        if let Some(app) = &self.github_app
+            && self.source.kind() == "github"
+        {
+            config.headers.push((
+                "Authorization".to_owned(),
+                format!("Bearer {}", app.installation_token()?),
+            ));
+        }
+        if let Some(client) = &self.client {
+            client.configure(&mut config);
+        }
+        let start = Instant::now();
+        let result =
+            crate::http::with_config(Some(Arc::new(config)), || self.check_source(&self.source));
+        if let (Some(callback), Some(stats)) = (&self.on_metrics, stats) {
+            callback(&CheckMetrics {
+                source: self.source.kind(),
+                duration: start.elapsed(),
+                status: Some(stats.status.load(Ordering::Relaxed)).filter(|status| *status != 0),
+                bytes: stats.bytes.load(Ordering::Relaxed),
+                cache_hit: stats.cache_hit.load(Ordering::Relaxed),
+            });
+        }
+        let mut info = result?;
+        if let Some(method) = self.install_method
+            && method != InstallMethod::Unknown
+        {
+            info.upgrade_command = method.upgrade_command(&self.name);
+        }
+        if let Some(path) = &self.history
+            && let Ok(mut history) = crate::UpdateHistory::load(path.clone())
+        {
+            history.record(&info);
+            // The history is informational, so failing to save it is ignored.
+            let _ = history.save();
+        }
+        Ok(CheckOutcome::Checked(info))
+    }
+
+    /// Checks the source, honoring the asset requirement.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source cannot be checked.
+    #[cfg(feature = "blocking")]
+    fn check_source(&self, source: &Source) -> anyhow::Result<UpdateInfo> {
+        let pattern = self.asset_pattern.as_deref();
+        if pattern.is_none() && !self.highest_version {
+            return source.check(&self.name, &self.current_version);
+        }
+        Ok(match (pattern, source) {
+            (_, Source::Github(user)) => {
+                let update_available = UpdateAvailable::new(&self.name, &self.current_version);
+                let (_, releases) = update_available.github_urls(user);
+                let mut info = update_available.highest_release(
+                    &releases,
+                    "GitHub",
+                    crate::logic::GITHUB_RAW_PATH,
+                    pattern,
+                )?;
+                info.upgrade_command = Some(update_available.github_upgrade_command(user));
+                info
+            }
+            (_, Source::Gitea(user, gitea_url)) => {
+                let update_available = UpdateAvailable::new(&self.name, &self.current_version);
+                let gitea_url = crate::config::forge_url(gitea_url)?;
+                let (_, releases) = update_available.gitea_urls(user, &gitea_url);
+                update_available.highest_release(
+                    &releases,
+                    "Gitea",
+                    crate::logic::GITEA_RAW_PATH,
+                    pattern,
+                )?
+            }
+            (_, Source::Headers { source, headers }) => {
+                crate::http::with_headers(headers, || self.check_source(source))?
+            }
+            (Some(_), _) => {
+                anyhow::bail!("Asset requirements are only supported for GitHub and Gitea")
+            }
+            (None, _) => {
+                anyhow::bail!("The highest version can only be picked on GitHub and Gitea")
+            }
+        })
+    }
+}
+
+/// Returns whether a TCP connection to `address` can be opened within `timeout`.
+///
+/// Every resolved address is tried, each with the full timeout.
+fn is_reachable(address: &str, timeout: Duration) -> bool {
+    address.to_socket_addrs().is_ok_and(|mut addresses| {
+        addresses.any(|address| TcpStream::connect_timeout(&address, timeout).is_ok())
+    })
+}
+
+/// Returns whether an environment variable value enables a flag.
+///
+/// Empty values, `0` and `false` are treated as unset.
+pub(crate) fn is_enabled(value: &str) -> bool {
+    !value.is_empty() && value != "0" && !value.eq_ignore_ascii_case("false")
+}
+
+/// Determines why a check should be skipped, looking variables up with `env`.
+pub(crate) fn skip_reason(
+    opt_out_env: Option<&str>,
+    skip_in_ci: bool,
+    env: impl Fn(&str) -> Option<String>,
+) -> Option<SkipReason> {
+    let opted_out = [Some(NO_UPDATE_CHECK), opt_out_env]
+        .into_iter()
+        .flatten()
+        .find(|variable| env(variable).is_some_and(|value| is_enabled(&value)));
+    if let Some(variable) = opted_out {
+        return Some(SkipReason::OptedOut(variable.to_owned()));
+    }
+    (skip_in_ci && env("CI").is_some_and(|value| is_enabled(&value))).then_some(SkipReason::Ci)
+}
+
+/// Returns whether the user opted out of update checks via [`NO_UPDATE_CHECK`],
+/// or all checks were turned off with [`disable`].
+pub(crate) fn opted_out() -> bool {
+    is_disabled() || skip_reason(None, false, |variable| std::env::var(variable).ok()).is_some()
+}
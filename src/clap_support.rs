@@ -0,0 +1,97 @@
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use crate::{CheckOutcome, Checker, Messages, SkipReason};
+
+/// The name of the flag and subcommand added by [`with_check_update`] and
+/// [`with_check_update_subcommand`].
+pub const CHECK_UPDATE: &str = "check-update";
+
+/// Adds a `--check-update` flag to a clap command.
+///
+/// Pass the matches to [`handle_check_update`] to run the check when the
+/// flag is given.
+///
+/// # Examples
+///
+/// ```rust
+/// use clap::Command;
+/// use update_available::{Checker, Source, handle_check_update, with_check_update};
+///
+/// let matches = with_check_update(Command::new("my-tool")).get_matches_from(["my-tool"]);
+/// let checker = Checker::new("my-tool", "1.0.0", Source::CratesIo);
+/// if let Some(result) = handle_check_update(&matches, &checker) {
+///     std::process::exit(i32::from(result.is_err()));
+/// }
+/// ```
+#[must_use]
+pub fn with_check_update(command: Command) -> Command {
+    command.arg(
+        Arg::new(CHECK_UPDATE)
+            .long(CHECK_UPDATE)
+            .action(ArgAction::SetTrue)
+            .help("Check for a newer version and exit"),
+    )
+}
+
+/// Adds a `check-update` subcommand to a clap command.
+///
+/// Pass the matches to [`handle_check_update`] to run the check when the
+/// subcommand is used.
+#[must_use]
+pub fn with_check_update_subcommand(command: Command) -> Command {
+    command.subcommand(Command::new(CHECK_UPDATE).about("Check for a newer version"))
+}
+
+/// Runs the update check if the `--check-update` flag or the `check-update`
+/// subcommand was used.
+///
+/// The result is printed to the [`crate::output`] stream, stderr by default:
+/// the notification if an update is available, or a short message otherwise.
+/// Errors are returned without printing, so the application can report them
+/// in its own style.
+///
+/// # Arguments
+///
+/// * `matches` - The matches of a command prepared with [`with_check_update`]
+///   or [`with_check_update_subcommand`]
+/// * `checker` - The checker to run
+///
+/// # Returns
+///
+/// Returns `None` if no check was requested, otherwise the outcome of the check.
+#[must_use]
+pub fn handle_check_update(
+    matches: &ArgMatches,
+    checker: &Checker,
+) -> Option<anyhow::Result<CheckOutcome>> {
+    let flag = matches
+        .try_get_one::<bool>(CHECK_UPDATE)
+        .is_ok_and(|flag| flag.copied().unwrap_or_default());
+    if !flag && matches.subcommand_name() != Some(CHECK_UPDATE) {
+        return None;
+    }
+    let result = checker.check();
+    if let Ok(outcome) = &result {
+        print_outcome(outcome);
+    }
+    Some(result)
+}
+
+/// Prints the outcome of a requested update check to the [`crate::output`]
+/// stream, in the default [`Messages`].
+fn print_outcome(outcome: &CheckOutcome) {
+    let messages = Messages::default();
+    let output = crate::output();
+    match outcome {
+        CheckOutcome::Checked(info) if info.is_update_available => info.print(),
+        CheckOutcome::Checked(info) => {
+            output.print_line(crate::display::up_to_date_line(info, &messages));
+        }
+        CheckOutcome::Skipped(SkipReason::OptedOut(variable)) => {
+            output.print_line(format_args!("{} {variable}", messages.disabled_by));
+        }
+        CheckOutcome::Skipped(SkipReason::Ci) => output.print_line(messages.disabled_in_ci),
+        CheckOutcome::Skipped(SkipReason::Disabled) => output.print_line(messages.disabled),
+        CheckOutcome::Offline => output.print_line(messages.offline),
+    }
+}
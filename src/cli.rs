@@ -0,0 +1,474 @@
+//! Command-line interface of the `update-available` binary.
+//!
+//! This module is only available with the `cli` feature enabled.
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
+use semver::{Version, VersionReq};
+use serde::{Deserialize, Serialize};
+
+use crate::{NotifyPolicy, Source, UpdateInfo, UpdateKind, UpdateSource as _};
+
+/// The exit code used when an update is available.
+const EXIT_UPDATE_AVAILABLE: u8 = 10;
+
+/// Check for updates of a crate on crates.io, GitHub or Gitea.
+#[derive(Parser)]
+#[command(
+    name = "update-available",
+    version,
+    after_help = "Exit codes: 0 = up to date, 10 = update available, 1 = error"
+)]
+pub struct Cli {
+    /// The output format.
+    #[arg(long, value_enum, global = true, default_value_t = Format::Plain)]
+    format: Format,
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Check the current package for updates, as a cargo subcommand.
+#[derive(Parser)]
+#[command(name = "cargo", bin_name = "cargo")]
+enum CargoCli {
+    /// Check the current package for updates on the source it is published to.
+    #[command(
+        version,
+        after_help = "Exit codes: 0 = up to date, 10 = update available, 1 = error"
+    )]
+    UpdateAvailable(CargoArgs),
+}
+
+/// Arguments of the `cargo update-available` subcommand.
+#[derive(Args)]
+struct CargoArgs {
+    /// The path to the package's `Cargo.toml`.
+    #[arg(long, value_name = "PATH", default_value = "Cargo.toml")]
+    manifest_path: PathBuf,
+    /// The output format.
+    #[arg(long, value_enum, default_value_t = Format::Plain)]
+    format: Format,
+}
+
+/// The subset of `cargo metadata` output needed to determine the source.
+#[derive(Deserialize)]
+pub(crate) struct Metadata {
+    pub(crate) packages: Vec<MetadataPackage>,
+}
+
+/// A workspace package in `cargo metadata` output.
+#[derive(Deserialize)]
+pub(crate) struct MetadataPackage {
+    name: String,
+    version: Version,
+    repository: Option<String>,
+    publish: Option<Vec<String>>,
+    manifest_path: PathBuf,
+}
+
+impl MetadataPackage {
+    /// Determines the name to check and the source the package is published to.
+    ///
+    /// Packages publishable to crates.io are checked there; otherwise the
+    /// `repository` URL decides between GitHub and Gitea.
+    pub(crate) fn source(&self) -> anyhow::Result<(String, Source)> {
+        let publishable = self
+            .publish
+            .as_ref()
+            .is_none_or(|registries| registries.iter().any(|registry| registry == "crates-io"));
+        if publishable {
+            return Ok((self.name.clone(), Source::CratesIo));
+        }
+        let repository = self.repository.as_deref().ok_or_else(|| {
+            anyhow::anyhow!(
+                "{} is not published to crates.io and has no repository",
+                self.name
+            )
+        })?;
+        let (source, repo) = Source::parse_with_repository(repository)?;
+        let repo =
+            repo.ok_or_else(|| anyhow::anyhow!("Unsupported repository URL '{repository}'"))?;
+        Ok((repo, source))
+    }
+}
+
+/// The output format of the CLI.
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Human-readable output.
+    Plain,
+    /// Machine-readable JSON output.
+    Json,
+}
+
+/// The available subcommands.
+#[derive(Subcommand)]
+enum Command {
+    /// Check a single package for updates.
+    Check(CheckArgs),
+    /// Check all crates.io dependencies of a cargo workspace.
+    Scan {
+        /// The path to the workspace root `Cargo.toml`.
+        #[arg(default_value = "Cargo.toml")]
+        manifest_path: PathBuf,
+    },
+    /// Check all binaries installed with `cargo install`.
+    Installed,
+}
+
+/// Arguments of the `check` subcommand.
+#[derive(Args)]
+#[command(group(ArgGroup::new("source").required(true).args(["crates_io", "github", "gitea", "packagist"])))]
+struct CheckArgs {
+    /// Check the crate with this name on crates.io.
+    #[arg(long, value_name = "NAME")]
+    crates_io: Option<String>,
+    /// Check the latest release of a GitHub repository.
+    #[arg(long, value_name = "USER/REPO")]
+    github: Option<String>,
+    /// Check the latest release of a Gitea repository.
+    #[arg(long, value_name = "USER/REPO", requires = "gitea_url")]
+    gitea: Option<String>,
+    /// The base URL of the Gitea instance (e.g. <https://gitea.example.com>).
+    #[arg(long, value_name = "URL")]
+    gitea_url: Option<String>,
+    /// Check the Composer package with this name on Packagist.
+    #[arg(long, value_name = "VENDOR/PACKAGE")]
+    packagist: Option<String>,
+    /// The currently installed version.
+    #[arg(long, value_name = "VERSION")]
+    current: String,
+    /// Only report updates matching this version requirement (e.g. `^1`).
+    #[arg(long, value_name = "REQ")]
+    requirement: Option<VersionReq>,
+    /// Only report updates at least this severe.
+    #[arg(long, value_enum, value_name = "KIND")]
+    at_least: Option<UpdateKind>,
+}
+
+impl CheckArgs {
+    /// Performs the check against the selected source and applies the policy.
+    fn check(&self) -> anyhow::Result<UpdateInfo> {
+        let mut policy = NotifyPolicy::new();
+        if let Some(requirement) = &self.requirement {
+            policy = policy.requirement(requirement.clone());
+        }
+        if let Some(kind) = self.at_least {
+            policy = policy.notify_at_least(kind);
+        }
+        Ok(self.check_source()?.with_policy(&policy))
+    }
+
+    /// Performs the check against the selected source.
+    fn check_source(&self) -> anyhow::Result<UpdateInfo> {
+        if let Some(name) = &self.crates_io {
+            crate::check_crates_io(name, &self.current)
+        } else if let Some(repo) = &self.github {
+            let (user, name) = split_repo(repo)?;
+            crate::check_github(name, user, &self.current)
+        } else if let (Some(repo), Some(gitea_url)) = (&self.gitea, &self.gitea_url) {
+            let (user, name) = split_repo(repo)?;
+            crate::check_gitea(name, user, gitea_url, &self.current)
+        } else if let Some(name) = &self.packagist {
+            Source::Packagist.check(name, &self.current)
+        } else {
+            anyhow::bail!("No source specified")
+        }
+    }
+}
+
+/// Splits a `user/repo` string into its user and repository parts.
+fn split_repo(repo: &str) -> anyhow::Result<(&str, &str)> {
+    repo.split_once('/')
+        .filter(|(user, name)| !user.is_empty() && !name.is_empty() && !name.contains('/'))
+        .ok_or_else(|| anyhow::anyhow!("Expected a repository in the form USER/REPO, got '{repo}'"))
+}
+
+/// The JSON output of a single dependency or installed package.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum PackageOutput<'a> {
+    /// The check succeeded.
+    Checked(&'a UpdateInfo),
+    /// The check failed.
+    Failed {
+        name: &'a str,
+        current_version: &'a Version,
+        error: String,
+    },
+}
+
+impl<'a> PackageOutput<'a> {
+    /// Creates the output for a package and the result of its check.
+    fn new(
+        name: &'a str,
+        current_version: &'a Version,
+        result: &'a anyhow::Result<UpdateInfo>,
+    ) -> Self {
+        match result {
+            Ok(info) => Self::Checked(info),
+            Err(e) => Self::Failed {
+                name,
+                current_version,
+                error: format!("{e:#}"),
+            },
+        }
+    }
+}
+
+/// The JSON output of a single workspace member.
+#[derive(Serialize)]
+struct MemberOutput<'a> {
+    name: &'a str,
+    manifest_path: &'a Path,
+    dependencies: Vec<PackageOutput<'a>>,
+}
+
+/// Parses the command-line arguments and runs the requested command.
+///
+/// The exit code is `0` if everything is up to date, `10` if an update is
+/// available and `1` if the check could not be performed.
+#[must_use]
+pub fn run() -> ExitCode {
+    let cli = Cli::parse();
+    match execute(&cli) {
+        Ok(false) => ExitCode::SUCCESS,
+        Ok(true) => ExitCode::from(EXIT_UPDATE_AVAILABLE),
+        Err(e) => {
+            eprintln!("Error: {e:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Runs the binary as `cargo update-available`.
+///
+/// The package's name, version and repository are read from `cargo metadata`
+/// and the source it is published to is checked. The exit code is `0` if
+/// everything is up to date, `10` if an update is available and `1` if the
+/// check could not be performed.
+#[must_use]
+pub fn run_cargo() -> ExitCode {
+    let CargoCli::UpdateAvailable(args) = CargoCli::parse();
+    match execute_cargo(&args) {
+        Ok(false) => ExitCode::SUCCESS,
+        Ok(true) => ExitCode::from(EXIT_UPDATE_AVAILABLE),
+        Err(e) => {
+            eprintln!("Error: {e:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Checks the packages of the given manifest, printing the results to stdout
+/// and the packages that could not be checked to stderr.
+///
+/// If the manifest is a virtual workspace, every member is checked. Every
+/// package is reported before an error is returned for those that failed.
+/// Returns whether any update is available.
+fn execute_cargo(args: &CargoArgs) -> anyhow::Result<bool> {
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let output = std::process::Command::new(cargo)
+        .args([
+            "metadata",
+            "--no-deps",
+            "--format-version",
+            "1",
+            "--manifest-path",
+        ])
+        .arg(&args.manifest_path)
+        .output()?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "cargo metadata failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    let metadata: Metadata = serde_json::from_slice(&output.stdout)?;
+    let manifest_path = args.manifest_path.canonicalize()?;
+    let mut packages: Vec<&MetadataPackage> = metadata
+        .packages
+        .iter()
+        .filter(|package| package.manifest_path == manifest_path)
+        .collect();
+    if packages.is_empty() {
+        packages = metadata.packages.iter().collect();
+    }
+
+    let results: Vec<(&MetadataPackage, anyhow::Result<UpdateInfo>)> = packages
+        .into_iter()
+        .map(|package| {
+            let result = package.source().and_then(|(name, source)| {
+                crate::check_source(&name, &package.version.to_string(), source)
+            });
+            (package, result)
+        })
+        .collect();
+    match args.format {
+        Format::Plain => {
+            for (package, result) in &results {
+                match result {
+                    Ok(info) => print_plain(info),
+                    Err(e) => eprintln!("❌  {}: {e:#}", package.name),
+                }
+            }
+        }
+        Format::Json => {
+            let output: Vec<PackageOutput<'_>> = results
+                .iter()
+                .map(|(package, result)| {
+                    PackageOutput::new(&package.name, &package.version, result)
+                })
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&output)?);
+        }
+    }
+    outcome(results.iter().map(|(_, result)| result))
+}
+
+/// Returns whether any check found an update.
+///
+/// # Errors
+///
+/// Returns an error naming how many packages could not be checked, if any,
+/// so a failed check never exits as up to date.
+pub(crate) fn outcome<'a>(
+    results: impl IntoIterator<Item = &'a anyhow::Result<UpdateInfo>>,
+) -> anyhow::Result<bool> {
+    let (mut total, mut failed, mut update_available) = (0, 0, false);
+    for result in results {
+        total += 1;
+        match result {
+            Ok(info) => update_available |= info.is_update_available,
+            Err(_) => failed += 1,
+        }
+    }
+    if failed > 0 {
+        anyhow::bail!("{failed} of {total} packages could not be checked");
+    }
+    Ok(update_available)
+}
+
+/// Prints the result of a single check in the plain format.
+fn print_plain(info: &UpdateInfo) {
+    if info.is_update_available {
+        print!("{info}");
+    } else {
+        println!(
+            "{}",
+            crate::display::up_to_date_line(info, &crate::Messages::default())
+        );
+    }
+}
+
+/// Executes the parsed command, printing the results to stdout.
+///
+/// Returns whether any update is available.
+fn execute(cli: &Cli) -> anyhow::Result<bool> {
+    match &cli.command {
+        Command::Check(args) => check(args, cli.format),
+        Command::Scan { manifest_path } => scan(manifest_path, cli.format),
+        Command::Installed => installed(cli.format),
+    }
+}
+
+/// Runs the `check` subcommand.
+fn check(args: &CheckArgs, format: Format) -> anyhow::Result<bool> {
+    let info = args.check()?;
+    match format {
+        Format::Plain => print_plain(&info),
+        Format::Json => println!("{}", info.to_json_string()?),
+    }
+    Ok(info.is_update_available)
+}
+
+/// Runs the `scan` subcommand.
+fn scan(manifest_path: &Path, format: Format) -> anyhow::Result<bool> {
+    let report = crate::scan_workspace(manifest_path)?;
+    match format {
+        Format::Plain => {
+            for member in &report.members {
+                println!("📦  {}", member.name);
+                for dependency in &member.dependencies {
+                    match &dependency.result {
+                        Ok(info) if info.is_update_available => println!(
+                            "    {} {} -> {}",
+                            dependency.name, dependency.current_version, info.latest_version
+                        ),
+                        Ok(_) => {}
+                        Err(e) => eprintln!("    {} failed: {e:#}", dependency.name),
+                    }
+                }
+            }
+        }
+        Format::Json => {
+            let output: Vec<MemberOutput<'_>> = report
+                .members
+                .iter()
+                .map(|member| MemberOutput {
+                    name: &member.name,
+                    manifest_path: &member.manifest_path,
+                    dependencies: member
+                        .dependencies
+                        .iter()
+                        .map(|dependency| {
+                            PackageOutput::new(
+                                &dependency.name,
+                                &dependency.current_version,
+                                &dependency.result,
+                            )
+                        })
+                        .collect(),
+                })
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&output)?);
+        }
+    }
+    outcome(
+        report
+            .members
+            .iter()
+            .flat_map(|member| &member.dependencies)
+            .map(|dependency| &dependency.result),
+    )
+}
+
+/// Runs the `installed` subcommand.
+fn installed(format: Format) -> anyhow::Result<bool> {
+    let report = crate::check_installed()?;
+    match format {
+        Format::Plain => {
+            for installed in &report.crates {
+                match &installed.result {
+                    Ok(info) if info.is_update_available => println!(
+                        "{} {} -> {} ({})",
+                        installed.name,
+                        installed.current_version,
+                        info.latest_version,
+                        installed.bins.join(", ")
+                    ),
+                    Ok(_) => {}
+                    Err(e) => eprintln!("{} failed: {e:#}", installed.name),
+                }
+            }
+        }
+        Format::Json => {
+            let output: Vec<PackageOutput<'_>> = report
+                .crates
+                .iter()
+                .map(|installed| {
+                    PackageOutput::new(
+                        &installed.name,
+                        &installed.current_version,
+                        &installed.result,
+                    )
+                })
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&output)?);
+        }
+    }
+    outcome(report.crates.iter().map(|installed| &installed.result))
+}
@@ -0,0 +1,387 @@
+use core::str::FromStr;
+use core::time::Duration;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use crate::data::UpdateInfo;
+use crate::http::{RateLimiter, RequestConfig};
+use crate::{BatchLimits, Checker, Endpoints, Source, UpdateReport, UpdateSource as _};
+
+/// Checks for updates within per-host request budgets.
+///
+/// Every check of a client, and of every [`Checker`] created from it, draws
+/// from the same token bucket per host, so batched and periodic checks slow
+/// down instead of running into the throttling of a service. Clones share
+/// the budgets.
+///
+/// A new client allows one request per second to crates.io, as asked by its
+/// crawler policy.
+///
+/// All clients share one pool of keep-alive connections, so a batch reuses
+/// connections instead of paying for a TLS handshake per request. HTTP/2 is
+/// not supported: requests are sent over HTTP/1.1 and never multiplexed, so
+/// [`BatchLimits`] bounds how many connections a batch opens per host.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use update_available::{BatchLimits, Source, UpdateClient};
+///
+/// let client = UpdateClient::new().rate_limit("api.github.com", 10, Duration::from_mins(1));
+/// let report = client.check_many(
+///     &[("serde", "1.0.0"), ("anyhow", "1.0.0")],
+///     &Source::CratesIo,
+///     BatchLimits::default(),
+/// );
+/// print!("{report}");
+/// ```
+#[derive(Clone)]
+pub struct UpdateClient {
+    rate_limiter: Arc<RateLimiter>,
+    resolve: Arc<HashMap<String, Vec<IpAddr>>>,
+    ip_family: Option<IpFamily>,
+    endpoints: Option<Arc<Endpoints>>,
+    max_body_size: Option<u64>,
+    allow_http: Option<bool>,
+    max_redirects: Option<u32>,
+    block_private_redirects: bool,
+}
+
+/// The address families connected to, and their order.
+///
+/// Hosts with both IPv4 and IPv6 addresses are tried in the order of the
+/// resolver, falling back to the next address if a connection fails. On
+/// networks with broken IPv6 every check then waits for the IPv6 attempt to
+/// time out first; preferring or requiring IPv4 avoids that.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IpFamily {
+    /// Connects to the addresses in the order of the resolver.
+    #[default]
+    Any,
+    /// Tries IPv4 addresses before IPv6 addresses.
+    PreferIpv4,
+    /// Tries IPv6 addresses before IPv4 addresses.
+    PreferIpv6,
+    /// Only connects to IPv4 addresses.
+    Ipv4Only,
+    /// Only connects to IPv6 addresses.
+    Ipv6Only,
+}
+
+impl IpFamily {
+    /// Returns whether `address` may be connected to.
+    pub(crate) const fn allows(self, address: IpAddr) -> bool {
+        match self {
+            Self::Any | Self::PreferIpv4 | Self::PreferIpv6 => true,
+            Self::Ipv4Only => address.is_ipv4(),
+            Self::Ipv6Only => address.is_ipv6(),
+        }
+    }
+
+    /// Returns the rank of `address` when ordering the addresses to try,
+    /// lower first.
+    pub(crate) const fn rank(self, address: IpAddr) -> u8 {
+        match self {
+            Self::PreferIpv4 => address.is_ipv6() as u8,
+            Self::PreferIpv6 => address.is_ipv4() as u8,
+            Self::Any | Self::Ipv4Only | Self::Ipv6Only => 0,
+        }
+    }
+}
+
+impl FromStr for IpFamily {
+    type Err = anyhow::Error;
+
+    /// Parses `any`, `prefer-ipv4`, `prefer-ipv6`, `ipv4` or `ipv6`,
+    /// ignoring case.
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "any" => Ok(Self::Any),
+            "prefer-ipv4" => Ok(Self::PreferIpv4),
+            "prefer-ipv6" => Ok(Self::PreferIpv6),
+            "ipv4" => Ok(Self::Ipv4Only),
+            "ipv6" => Ok(Self::Ipv6Only),
+            _ => Err(anyhow::anyhow!(
+                "Unknown IP family '{s}', expected any, prefer-ipv4, prefer-ipv6, ipv4 or ipv6"
+            )),
+        }
+    }
+}
+
+impl UpdateClient {
+    /// Creates a client allowing one request per second to crates.io.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            rate_limiter: Arc::default(),
+            resolve: Arc::default(),
+            ip_family: None,
+            endpoints: None,
+            max_body_size: None,
+            allow_http: None,
+            max_redirects: None,
+            block_private_redirects: false,
+        }
+        .rate_limit("crates.io", 1, Duration::from_secs(1))
+    }
+
+    /// Allows bursts of `requests` requests to `host`, refilled evenly over
+    /// `per`.
+    ///
+    /// Requests beyond the budget wait until a token is available. Replaces
+    /// the previous budget of the host, also for clones of the client.
+    /// Hosts without a budget are not limited.
+    ///
+    /// # Arguments
+    ///
+    /// * `host` - The host name, e.g. `crates.io`
+    /// * `requests` - The number of requests allowed per `per`, at least 1
+    /// * `per` - The period the budget refills over
+    #[must_use]
+    pub fn rate_limit(self, host: &str, requests: u32, per: Duration) -> Self {
+        self.rate_limiter.set(host, requests, per);
+        self
+    }
+
+    /// Connects to `addresses` instead of looking up `host` in the system
+    /// DNS, like curl's `--resolve`.
+    ///
+    /// Useful in containers with unusual DNS setups and to pin internal
+    /// hosts, such as a Gitea server, to known addresses. The port of the URL
+    /// is kept, and host names are matched case-insensitively. Unlike the
+    /// request budgets, overrides only apply to this client and the clones
+    /// made after adding them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::net::{IpAddr, Ipv4Addr};
+    /// use update_available::{Source, UpdateClient};
+    ///
+    /// let client = UpdateClient::new().resolve(
+    ///     "gitea.example.com",
+    ///     [IpAddr::V4(Ipv4Addr::new(10, 0, 0, 5))],
+    /// );
+    /// let source = Source::Gitea("user".into(), "https://gitea.example.com".into());
+    /// let result = client.check("repo", "1.0.0", &source);
+    /// ```
+    #[must_use]
+    pub fn resolve(mut self, host: &str, addresses: impl IntoIterator<Item = IpAddr>) -> Self {
+        Arc::make_mut(&mut self.resolve)
+            .insert(host.to_ascii_lowercase(), addresses.into_iter().collect());
+        self
+    }
+
+    /// Selects the address families connected to, overriding
+    /// `UPDATE_AVAILABLE_IP_FAMILY`.
+    ///
+    /// Applies to resolved addresses and to those of [`Self::resolve`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::{IpFamily, Source, UpdateClient};
+    ///
+    /// let client = UpdateClient::new().ip_family(IpFamily::PreferIpv4);
+    /// let result = client.check("serde", "1.0.0", &Source::CratesIo);
+    /// ```
+    #[must_use]
+    pub const fn ip_family(mut self, family: IpFamily) -> Self {
+        self.ip_family = Some(family);
+        self
+    }
+
+    /// Queries mirrors or caching proxies instead of the public APIs of
+    /// crates.io and GitHub.
+    ///
+    /// Overrides the `UPDATE_AVAILABLE_*_URL` variables for the URLs set in
+    /// `endpoints`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::{Endpoints, Source, UpdateClient};
+    ///
+    /// let client = UpdateClient::new().endpoints(Endpoints {
+    ///     sparse_index: Some("sparse+https://index.crates.io/".to_owned()),
+    ///     ..Endpoints::default()
+    /// });
+    /// let result = client.check("serde", "1.0.0", &Source::CratesIo);
+    /// ```
+    #[must_use]
+    pub fn endpoints(mut self, endpoints: Endpoints) -> Self {
+        self.endpoints = Some(Arc::new(endpoints));
+        self
+    }
+
+    /// Limits the bytes read from any response, overriding
+    /// `UPDATE_AVAILABLE_MAX_BODY_SIZE`.
+    ///
+    /// Protects applications checking user-configured sources, such as a
+    /// custom Gitea URL, from endpoints sending endless responses. Bodies are
+    /// counted after decompression, and a response exceeding the limit fails
+    /// the check. Defaults to 10 MiB, which is also the most that is ever
+    /// read, so only lower limits take effect.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::{Source, UpdateClient};
+    ///
+    /// let client = UpdateClient::new().max_body_size(1024 * 1024);
+    /// let result = client.check("serde", "1.0.0", &Source::CratesIo);
+    /// ```
+    #[must_use]
+    pub const fn max_body_size(mut self, bytes: u64) -> Self {
+        self.max_body_size = Some(bytes);
+        self
+    }
+
+    /// Allows or refuses plain `http://` URLs for self-hosted forges, such as
+    /// the URL of a [`Source::Gitea`], overriding
+    /// `UPDATE_AVAILABLE_ALLOW_HTTP`.
+    ///
+    /// Refused by default, so tokens and passwords are not sent unencrypted
+    /// because of a mistyped URL. Loopback hosts, such as `localhost`, are
+    /// always allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::{Source, UpdateClient};
+    ///
+    /// let client = UpdateClient::new().allow_http(true);
+    /// let source = Source::Gitea("user".into(), "http://gitea.lan:3000".into());
+    /// let result = client.check("repo", "1.0.0", &source);
+    /// ```
+    #[must_use]
+    pub const fn allow_http(mut self, allow: bool) -> Self {
+        self.allow_http = Some(allow);
+        self
+    }
+
+    /// Limits the redirects followed per request, 5 by default.
+    ///
+    /// A request redirected more often fails; with `0`, redirect responses
+    /// are not followed. Redirects from HTTPS to plain HTTP are always
+    /// refused. A redirect to another scheme, host or port drops the
+    /// `Authorization`, `Cookie` and `Proxy-Authorization` headers and the
+    /// headers added to the source.
+    #[must_use]
+    pub const fn max_redirects(mut self, redirects: u32) -> Self {
+        self.max_redirects = Some(redirects);
+        self
+    }
+
+    /// Refuses redirects to private, loopback and link-local addresses, such
+    /// as `10.0.0.1`, `127.0.0.1` or the cloud metadata service at
+    /// `169.254.169.254`.
+    ///
+    /// For server applications checking sources configured by their users,
+    /// so a user-supplied URL cannot redirect checks into the internal
+    /// network. The target is checked when the redirect is followed and
+    /// again when connecting. The URLs of the sources themselves are not
+    /// restricted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::{Source, UpdateClient};
+    ///
+    /// let client = UpdateClient::new().block_private_redirects(true).max_redirects(2);
+    /// let source = Source::Gitea("user".into(), "https://gitea.example.com".into());
+    /// let result = client.check("repo", "1.0.0", &source);
+    /// ```
+    #[must_use]
+    pub const fn block_private_redirects(mut self, block: bool) -> Self {
+        self.block_private_redirects = block;
+        self
+    }
+
+    /// Checks a package on a source within the request budgets.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the package to check
+    /// * `current_version` - The current version string (e.g., "1.0.0")
+    /// * `source` - The source to check the package on
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source cannot be checked.
+    pub fn check(
+        &self,
+        name: &str,
+        current_version: &str,
+        source: &Source,
+    ) -> anyhow::Result<UpdateInfo> {
+        self.limited(|| source.check(name, current_version))
+    }
+
+    /// Checks many packages on a source within `limits` and the request
+    /// budgets.
+    ///
+    /// See [`crate::check_many_with_limits`].
+    #[must_use]
+    pub fn check_many(
+        &self,
+        packages: &[(&str, &str)],
+        source: &Source,
+        limits: BatchLimits,
+    ) -> UpdateReport {
+        self.limited(|| crate::check_many_with_limits(packages, source, limits))
+    }
+
+    /// Creates a checker whose requests draw from the request budgets.
+    ///
+    /// See [`Checker::client`].
+    #[must_use]
+    pub fn checker(&self, name: &str, current_version: &str, source: Source) -> Checker {
+        Checker::new(name, current_version, source).client(self)
+    }
+
+    /// Applies the rate limiter, host overrides, IP family, endpoints,
+    /// response size limit, HTTP permission and redirect policy to the
+    /// settings of a check.
+    pub(crate) fn configure(&self, config: &mut RequestConfig) {
+        config.rate_limiter = Some(Arc::clone(&self.rate_limiter));
+        if !self.resolve.is_empty() {
+            config.resolve = Some(Arc::clone(&self.resolve));
+        }
+        if self.ip_family.is_some() {
+            config.ip_family = self.ip_family;
+        }
+        if self.endpoints.is_some() {
+            config.endpoints.clone_from(&self.endpoints);
+        }
+        if self.max_body_size.is_some() {
+            config.max_body_size = self.max_body_size;
+        }
+        if self.allow_http.is_some() {
+            config.allow_http = self.allow_http;
+        }
+        if self.max_redirects.is_some() {
+            config.max_redirects = self.max_redirects;
+        }
+        config.block_private_redirects |= self.block_private_redirects;
+    }
+
+    /// Runs `f` with the rate limiter, host overrides, IP family, endpoints,
+    /// response size limit, HTTP permission and redirect policy applied to
+    /// its requests.
+    fn limited<T>(&self, f: impl FnOnce() -> T) -> T {
+        let mut config = crate::http::current()
+            .map(|config| (*config).clone())
+            .unwrap_or_default();
+        self.configure(&mut config);
+        crate::http::with_config(Some(Arc::new(config)), f)
+    }
+}
+
+impl Default for UpdateClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
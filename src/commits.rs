@@ -0,0 +1,96 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+use core::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// How far a build from git is behind the default branch of its repository.
+///
+/// Returned by [`crate::check_github_commits`] and
+/// [`crate::check_gitea_commits`] for nightly builds, whose version embeds
+/// the commit they were built from instead of a released version.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CommitInfo {
+    /// The name of the repository that was checked.
+    pub name: String,
+    /// The commit embedded in the current version, e.g. `abc1234`.
+    pub current_commit: String,
+    /// The commit at the head of the default branch.
+    pub latest_commit: String,
+    /// The default branch of the repository, e.g. `main`.
+    pub branch: String,
+    /// The number of commits on the default branch since the current commit.
+    pub commits_behind: u64,
+    /// The page comparing the current commit with the default branch.
+    pub url: String,
+}
+
+impl CommitInfo {
+    /// Returns whether the default branch has commits the build does not.
+    #[must_use]
+    pub const fn is_update_available(&self) -> bool {
+        self.commits_behind > 0
+    }
+}
+
+impl fmt::Display for CommitInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.commits_behind {
+            0 => write!(f, "{} is up to date with {}", self.name, self.branch),
+            1 => write!(
+                f,
+                "{} is 1 commit behind {}: {}",
+                self.name, self.branch, self.url
+            ),
+            behind => write!(
+                f,
+                "{} is {behind} commits behind {}: {}",
+                self.name, self.branch, self.url
+            ),
+        }
+    }
+}
+
+/// A repository, as returned by the GitHub and Gitea APIs.
+#[derive(Deserialize)]
+pub(crate) struct Repository {
+    pub(crate) default_branch: String,
+    pub(crate) html_url: String,
+}
+
+/// A branch, as returned by the GitHub and Gitea APIs.
+#[derive(Deserialize)]
+pub(crate) struct Branch {
+    pub(crate) commit: BranchCommit,
+}
+
+/// The head commit of a branch.
+#[derive(Deserialize)]
+pub(crate) struct BranchCommit {
+    #[serde(alias = "id")]
+    pub(crate) sha: String,
+}
+
+/// A comparison of two commits, as returned by the GitHub and Gitea APIs.
+#[derive(Deserialize)]
+pub(crate) struct Comparison {
+    pub(crate) total_commits: u64,
+}
+
+/// Extracts the commit from the version of a build from git.
+///
+/// Accepts an abbreviated or full hexadecimal commit hash as the last
+/// pre-release or build identifier, e.g. `1.2.3-abc1234`, `1.2.3+abc1234` or
+/// `1.2.3-5-gabc1234` as produced by `git describe`. Hashes consisting of
+/// digits only are only accepted with the `g` prefix, to not mistake build
+/// numbers for commits.
+pub(crate) fn commit_from_version(version: &str) -> Option<&str> {
+    let (_, suffix) = version.split_once(['-', '+'])?;
+    let identifier = suffix.rsplit(['-', '+', '.']).next()?;
+    let (hash, prefixed) = identifier
+        .strip_prefix('g')
+        .map_or((identifier, false), |hash| (hash, true));
+    ((7..=40).contains(&hash.len())
+        && hash.bytes().all(|byte| byte.is_ascii_hexdigit())
+        && (prefixed || !hash.bytes().all(|byte| byte.is_ascii_digit())))
+    .then_some(hash)
+}
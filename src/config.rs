@@ -0,0 +1,513 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+use core::str::FromStr;
+use core::time::Duration;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Deserializer};
+
+use crate::{Checker, DisplayStyle, Source};
+
+/// Turns off all update checks if set to a value other than `0` or `false`.
+pub(crate) const DISABLE_ENV: &str = "UPDATE_AVAILABLE_DISABLE";
+/// The timeout of every request, e.g. `10s`.
+pub(crate) const TIMEOUT_ENV: &str = "UPDATE_AVAILABLE_TIMEOUT";
+/// The base URL of the crates.io API, e.g. for a mirror.
+pub(crate) const CRATES_IO_URL_ENV: &str = "UPDATE_AVAILABLE_CRATES_IO_URL";
+/// The URL of a sparse crate index read instead of the crates.io API.
+pub(crate) const SPARSE_INDEX_URL_ENV: &str = "UPDATE_AVAILABLE_SPARSE_INDEX_URL";
+/// The base URL of the GitHub API, e.g. `https://github.example.com/api/v3`.
+pub(crate) const GITHUB_API_URL_ENV: &str = "UPDATE_AVAILABLE_GITHUB_API_URL";
+/// The address families connected to, e.g. `ipv4` or `prefer-ipv4`.
+pub(crate) const IP_FAMILY_ENV: &str = "UPDATE_AVAILABLE_IP_FAMILY";
+/// The most bytes read from a response.
+pub(crate) const MAX_BODY_SIZE_ENV: &str = "UPDATE_AVAILABLE_MAX_BODY_SIZE";
+/// Allows plain HTTP URLs for self-hosted forges if set to a value other
+/// than `0` or `false`.
+pub(crate) const ALLOW_HTTP_ENV: &str = "UPDATE_AVAILABLE_ALLOW_HTTP";
+/// The token sent to the GitHub API.
+pub(crate) const GITHUB_TOKEN_ENV: &str = "UPDATE_AVAILABLE_GITHUB_TOKEN";
+/// The token sent to Gitea instances.
+pub(crate) const GITEA_TOKEN_ENV: &str = "UPDATE_AVAILABLE_GITEA_TOKEN";
+/// The username sent to Gitea instances with basic authentication.
+pub(crate) const GITEA_USERNAME_ENV: &str = "UPDATE_AVAILABLE_GITEA_USERNAME";
+/// The password sent to Gitea instances with basic authentication.
+pub(crate) const GITEA_PASSWORD_ENV: &str = "UPDATE_AVAILABLE_GITEA_PASSWORD";
+
+/// Returns the value of an environment variable, treating empty values as unset.
+pub(crate) fn env_var(variable: &str) -> Option<String> {
+    std::env::var(variable)
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+}
+
+/// The base URLs of the APIs and indexes the built-in sources query.
+///
+/// Points checks at mirrors or caching proxies without changing the sources.
+/// Unset URLs fall back to the `UPDATE_AVAILABLE_*_URL` variables, then to
+/// the public services. Applied to the checks of an
+/// [`crate::UpdateClient`] with `UpdateClient::endpoints`.
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::Endpoints;
+///
+/// let endpoints = Endpoints {
+///     github_api: Some("https://github.example.com/api/v3".to_owned()),
+///     sparse_index: Some("https://index.crates.example.com/".to_owned()),
+///     ..Endpoints::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Endpoints {
+    /// The base URL of the crates.io API, e.g. `https://crates.io`.
+    pub crates_io: Option<String>,
+    /// The URL of a sparse crate index, e.g. `https://index.crates.io/`.
+    ///
+    /// If set, crates.io checks read the versions from the index instead of
+    /// the API. A `sparse+` prefix, as in Cargo's configuration, is ignored.
+    pub sparse_index: Option<String>,
+    /// The base URL of the GitHub API, e.g. `https://api.github.com`.
+    pub github_api: Option<String>,
+}
+
+/// Returns a URL of the endpoints of the current check, if set.
+#[cfg(feature = "blocking")]
+fn endpoint(field: impl Fn(&Endpoints) -> Option<&String>) -> Option<String> {
+    crate::http::current()?
+        .endpoints
+        .as_deref()
+        .and_then(field)
+        .cloned()
+}
+
+/// Returns `None`, as endpoints are only set on the blocking client.
+#[cfg(not(feature = "blocking"))]
+fn endpoint(_field: impl Fn(&Endpoints) -> Option<&String>) -> Option<String> {
+    None
+}
+
+/// Returns the base URL of the crates.io API, honoring
+/// [`Endpoints::crates_io`] and [`CRATES_IO_URL_ENV`].
+pub(crate) fn crates_io_url() -> String {
+    base_url(
+        endpoint(|endpoints| endpoints.crates_io.as_ref()).or_else(|| env_var(CRATES_IO_URL_ENV)),
+        "https://crates.io",
+    )
+}
+
+/// Returns the URL of the sparse index to read crates from, honoring
+/// [`Endpoints::sparse_index`] and [`SPARSE_INDEX_URL_ENV`], or `None` to
+/// use the crates.io API.
+#[cfg(feature = "blocking")]
+pub(crate) fn sparse_index_url() -> Option<String> {
+    let url = endpoint(|endpoints| endpoints.sparse_index.as_ref())
+        .or_else(|| env_var(SPARSE_INDEX_URL_ENV))?;
+    let url = url.trim();
+    Some(
+        url.strip_prefix("sparse+")
+            .unwrap_or(url)
+            .trim_end_matches('/')
+            .to_owned(),
+    )
+}
+
+/// Returns the base URL of the GitHub API, honoring [`Endpoints::github_api`]
+/// and [`GITHUB_API_URL_ENV`].
+pub(crate) fn github_api_url() -> String {
+    base_url(
+        endpoint(|endpoints| endpoints.github_api.as_ref()).or_else(|| env_var(GITHUB_API_URL_ENV)),
+        "https://api.github.com",
+    )
+}
+
+/// Returns whether plain HTTP URLs are allowed for self-hosted forges, as set
+/// by the client or with [`ALLOW_HTTP_ENV`].
+#[cfg(feature = "blocking")]
+fn allow_http() -> bool {
+    crate::http::current()
+        .and_then(|current| current.allow_http)
+        .unwrap_or_else(|| {
+            env_var(ALLOW_HTTP_ENV).is_some_and(|value| crate::checker::is_enabled(&value))
+        })
+}
+
+/// Returns whether plain HTTP URLs are allowed for self-hosted forges, as set
+/// with [`ALLOW_HTTP_ENV`].
+#[cfg(not(feature = "blocking"))]
+fn allow_http() -> bool {
+    env_var(ALLOW_HTTP_ENV).is_some_and(|value| crate::checker::is_enabled(&value))
+}
+
+/// Normalizes the base URL of a self-hosted forge, such as a Gitea or GitLab
+/// instance, honoring the current [`ALLOW_HTTP_ENV`] setting.
+///
+/// See [`normalize_forge_url`].
+///
+/// # Errors
+///
+/// Returns an error if the URL is invalid or uses plain HTTP without
+/// permission.
+pub(crate) fn forge_url(url: &str) -> anyhow::Result<String> {
+    normalize_forge_url(url, allow_http())
+}
+
+/// Normalizes the base URL of a self-hosted forge.
+///
+/// Surrounding whitespace and trailing `/` are removed, and URLs without a
+/// scheme get `https://`. Plain `http://` URLs are refused unless
+/// `allow_http` is set, so tokens are not sent unencrypted by accident;
+/// loopback hosts are exempt, as their traffic never leaves the machine.
+///
+/// # Errors
+///
+/// Returns an error if the URL has an unsupported scheme, no host, a query
+/// or fragment, or uses plain HTTP without permission.
+pub(crate) fn normalize_forge_url(url: &str, allow_http: bool) -> anyhow::Result<String> {
+    let trimmed = url.trim();
+    let (scheme, rest) = trimmed
+        .split_once("://")
+        .map_or(("https", trimmed), |(scheme, rest)| (scheme, rest));
+    let rest = rest.trim_end_matches('/');
+    let scheme = scheme.to_ascii_lowercase();
+    if scheme != "https" && scheme != "http" {
+        anyhow::bail!("Unsupported scheme '{scheme}' in URL '{url}', expected https");
+    }
+    let authority = rest.split('/').next().unwrap_or_default();
+    let host = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    if host.is_empty()
+        || host.starts_with(':')
+        || rest.contains(['?', '#'])
+        || rest.contains(char::is_whitespace)
+    {
+        anyhow::bail!("Invalid URL '{url}', expected e.g. https://git.example.com");
+    }
+    if scheme == "http" && !allow_http && !is_loopback(host) {
+        anyhow::bail!(
+            "Refusing to send requests to '{url}' over plain HTTP; use https:// or allow HTTP with UpdateClient::allow_http or {ALLOW_HTTP_ENV}"
+        );
+    }
+    Ok(format!("{scheme}://{rest}"))
+}
+
+/// Returns whether the host of an authority, e.g. `127.0.0.1:3000` or
+/// `[::1]`, is a loopback address or `localhost`.
+fn is_loopback(host: &str) -> bool {
+    let host = host.strip_prefix('[').map_or_else(
+        || host.split(':').next().unwrap_or_default(),
+        |bracketed| bracketed.split(']').next().unwrap_or_default(),
+    );
+    host.eq_ignore_ascii_case("localhost")
+        || host
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|address| address.is_loopback())
+}
+
+/// Returns the configured base URL without a trailing `/`, or the default.
+pub(crate) fn base_url(configured: Option<String>, default: &str) -> String {
+    configured.map_or_else(
+        || default.to_owned(),
+        |url| url.trim().trim_end_matches('/').to_owned(),
+    )
+}
+
+/// Returns the request timeout set with [`TIMEOUT_ENV`], ignoring invalid
+/// values.
+pub(crate) fn timeout() -> Option<Duration> {
+    env_var(TIMEOUT_ENV).and_then(|timeout| parse_duration(&timeout).ok())
+}
+
+/// Returns the IP family set with [`IP_FAMILY_ENV`], ignoring invalid values.
+#[cfg(feature = "blocking")]
+pub(crate) fn ip_family() -> Option<crate::IpFamily> {
+    env_var(IP_FAMILY_ENV).and_then(|family| family.parse().ok())
+}
+
+/// Returns the response size limit set with [`MAX_BODY_SIZE_ENV`] in bytes,
+/// ignoring invalid values.
+#[cfg(feature = "blocking")]
+pub(crate) fn max_body_size() -> Option<u64> {
+    env_var(MAX_BODY_SIZE_ENV).and_then(|size| size.trim().parse().ok())
+}
+
+/// Returns the `Authorization` header for a source from the token variables,
+/// looking variables up with `env`.
+///
+/// Only set for GitHub and Gitea sources, so tokens are not sent to other hosts.
+/// Gitea falls back to basic authentication if a username and password are
+/// set instead of a token.
+#[cfg(feature = "blocking")]
+pub(crate) fn env_authorization(
+    source: &Source,
+    env: impl Fn(&str) -> Option<String>,
+) -> Option<(String, String)> {
+    let token = match source {
+        Source::Github(_) => env(GITHUB_TOKEN_ENV)?,
+        Source::Gitea(..) => {
+            let Some(token) = env(GITEA_TOKEN_ENV) else {
+                let basic = crate::http::basic_authorization(
+                    &env(GITEA_USERNAME_ENV)?,
+                    &env(GITEA_PASSWORD_ENV)?,
+                );
+                return Some(("Authorization".to_owned(), basic));
+            };
+            token
+        }
+        Source::Headers { source, .. } => return env_authorization(source, env),
+        Source::CratesIo
+        | Source::Homebrew { .. }
+        | Source::Aur
+        | Source::Debian { .. }
+        | Source::Repology
+        | Source::Scoop { .. }
+        | Source::Terraform { .. }
+        | Source::Tauri { .. }
+        | Source::Oci { .. }
+        | Source::Packagist
+        | Source::JsonEndpoint { .. }
+        | Source::Git { .. }
+        | Source::Gitlab { .. }
+        | Source::GitlabPackages { .. }
+        | Source::File(_)
+        | Source::Chain(_)
+        | Source::Highest(_)
+        | Source::Custom(_) => return None,
+        #[cfg(feature = "feed")]
+        Source::Feed { .. } => return None,
+        #[cfg(feature = "scrape")]
+        Source::Scrape { .. } => return None,
+    };
+    Some(("Authorization".to_owned(), format!("Bearer {token}")))
+}
+
+/// The settings of a [`Checker`], read from a TOML file.
+///
+/// Lets an application's CLI and GUI frontends share one configuration.
+/// All fields are optional:
+///
+/// ```toml
+/// # The source, in a form accepted by `Source::parse_with_repository`
+/// source = "github:user/my-tool"
+/// # Sent as `Authorization: Bearer …`; `${VAR}` is replaced by a variable
+/// token = "${GITHUB_TOKEN}"
+/// opt_out_env = "MY_TOOL_NO_UPDATE_CHECK"
+/// skip_in_ci = true
+/// offline_probe = "300ms"
+/// # How often the application should check, in `ms`, `s`, `m`, `h` or `d`
+/// interval = "1d"
+/// style = "boxed"
+/// user_agent = "my-tool/1.2.0"
+/// require_asset = "*x86_64-pc-windows-msvc*.zip"
+/// highest_version = true
+///
+/// [headers]
+/// X-Api-Key = "${MY_TOOL_API_KEY}"
+/// ```
+///
+/// Tokens and header values are read from the environment when the checker
+/// is created, so the file contains no secrets. Values referencing an unset
+/// variable are left out, so a token is only sent if it is set.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use update_available::{CheckOutcome, CheckerConfig};
+///
+/// let config = CheckerConfig::from_path("update-check.toml").unwrap();
+/// let checker = config.checker("my-tool", env!("CARGO_PKG_VERSION")).unwrap();
+/// if let Ok(CheckOutcome::Checked(info)) = checker.check() {
+///     info.print_styled(config.style);
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CheckerConfig {
+    /// The source, e.g. `github:user/repo`. Defaults to crates.io.
+    #[serde(default)]
+    pub source: Option<String>,
+    /// The token sent as `Authorization: Bearer` header, e.g. `${GITHUB_TOKEN}`.
+    #[serde(default)]
+    pub token: Option<String>,
+    /// Extra headers sent with every request to the source.
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// The tool-specific variable users set to opt out, see
+    /// [`Checker::opt_out_env`].
+    #[serde(default)]
+    pub opt_out_env: Option<String>,
+    /// Whether to skip the check in CI, see [`Checker::skip_in_ci`].
+    #[serde(default)]
+    pub skip_in_ci: bool,
+    /// The timeout of the connectivity probe, see [`Checker::offline_probe`].
+    #[serde(default, deserialize_with = "deserialize_duration")]
+    pub offline_probe: Option<Duration>,
+    /// How often the application should check for updates.
+    ///
+    /// Checks are not scheduled by the library; use it to decide when to
+    /// check next.
+    #[serde(default, deserialize_with = "deserialize_duration")]
+    pub interval: Option<Duration>,
+    /// The display style of the notification.
+    #[serde(default)]
+    pub style: DisplayStyle,
+    /// The product sent in the `User-Agent`, see [`Checker::user_agent`].
+    #[serde(default)]
+    pub user_agent: Option<String>,
+    /// The glob pattern a release asset must match, see
+    /// [`Checker::require_asset_matching`].
+    #[serde(default)]
+    pub require_asset: Option<String>,
+    /// Whether to report the highest version instead of the newest release,
+    /// see [`Checker::highest_version`].
+    #[serde(default)]
+    pub highest_version: bool,
+}
+
+impl CheckerConfig {
+    /// Reads the configuration from a TOML file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid
+    /// configuration.
+    pub fn from_path(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read {}: {e}", path.display()))?
+            .parse()
+    }
+
+    /// Creates a checker for a package from the configuration.
+    ///
+    /// If the source names a repository, e.g. `github:user/repo`, the
+    /// repository name is checked instead of `name`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the package to check
+    /// * `current_version` - The current version string (e.g., "1.0.0")
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source is not a supported source.
+    pub fn checker(&self, name: &str, current_version: &str) -> anyhow::Result<Checker> {
+        self.checker_with_env(name, current_version, |variable| {
+            std::env::var(variable).ok()
+        })
+    }
+
+    /// Creates a checker, looking variables up with `env`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source is not a supported source.
+    pub(crate) fn checker_with_env(
+        &self,
+        name: &str,
+        current_version: &str,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> anyhow::Result<Checker> {
+        let (mut source, repository) = match &self.source {
+            Some(source) => Source::parse_with_repository(source)?,
+            None => (Source::CratesIo, None),
+        };
+        if let Some(token) = self
+            .token
+            .as_deref()
+            .and_then(|token| expand_env(token, &env))
+            .filter(|token| !token.is_empty())
+        {
+            source = source.with_header("Authorization", &format!("Bearer {token}"));
+        }
+        for (header, value) in &self.headers {
+            if let Some(value) = expand_env(value, &env) {
+                source = source.with_header(header, &value);
+            }
+        }
+        let mut checker = Checker::new(
+            repository.as_deref().unwrap_or(name),
+            current_version,
+            source,
+        )
+        .skip_in_ci(self.skip_in_ci);
+        if let Some(variable) = &self.opt_out_env {
+            checker = checker.opt_out_env(variable);
+        }
+        if let Some(timeout) = self.offline_probe {
+            checker = checker.offline_probe(timeout);
+        }
+        if let Some(product) = &self.user_agent {
+            checker = checker.user_agent(product);
+        }
+        if let Some(pattern) = &self.require_asset {
+            checker = checker.require_asset_matching(pattern);
+        }
+        if self.highest_version {
+            checker = checker.highest_version();
+        }
+        Ok(checker)
+    }
+}
+
+impl FromStr for CheckerConfig {
+    type Err = anyhow::Error;
+
+    /// Parses a configuration from TOML.
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let config: Self =
+            toml::from_str(s).map_err(|e| anyhow::anyhow!("Failed to parse configuration: {e}"))?;
+        if let Some(source) = &config.source {
+            Source::parse_with_repository(source)?;
+        }
+        Ok(config)
+    }
+}
+
+/// Replaces every `${VAR}` in `value` by the variable looked up with `env`.
+///
+/// Returns `None` if a referenced variable is unset.
+pub(crate) fn expand_env(value: &str, env: impl Fn(&str) -> Option<String>) -> Option<String> {
+    let mut expanded = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        expanded.push_str(&rest[..start]);
+        expanded.push_str(&env(&rest[start + 2..start + end])?);
+        rest = &rest[start + end + 1..];
+    }
+    expanded.push_str(rest);
+    Some(expanded)
+}
+
+/// Parses a duration like `300ms`, `30s`, `15m`, `12h` or `1d`.
+pub(crate) fn parse_duration(s: &str) -> anyhow::Result<Duration> {
+    let s = s.trim();
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (amount, unit) = s.split_at(split);
+    let amount: u64 = amount
+        .parse()
+        .map_err(|_| anyhow::anyhow!("Invalid duration '{s}'"))?;
+    let seconds = match unit.trim() {
+        "ms" => return Ok(Duration::from_millis(amount)),
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        _ => anyhow::bail!("Invalid duration '{s}', expected a unit of ms, s, m, h or d"),
+    };
+    Ok(Duration::from_secs(amount.saturating_mul(seconds)))
+}
+
+/// Deserializes an optional duration with [`parse_duration`].
+fn deserialize_duration<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Duration>, D::Error> {
+    Option::<String>::deserialize(deserializer)?
+        .map(|s| parse_duration(&s).map_err(serde::de::Error::custom))
+        .transpose()
+}
@@ -1,8 +1,9 @@
 #![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
-use core::fmt;
+use std::time::SystemTime;
+use std::{fs, path::Path};
 
 use semver::Version;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Internal structure for managing update checks.
 #[derive(Default)]
@@ -17,6 +18,173 @@ pub(crate) struct GiteaHubResponse {
     pub(crate) tag_name: String,
     pub(crate) body: Option<String>,
     pub(crate) html_url: String,
+    #[serde(default)]
+    pub(crate) draft: bool,
+    #[serde(default)]
+    pub(crate) prerelease: bool,
+    #[serde(default)]
+    pub(crate) assets: Vec<ReleaseAsset>,
+    /// When the release was published, in RFC 3339 format.
+    #[serde(default)]
+    pub(crate) published_at: Option<String>,
+    #[serde(default)]
+    pub(crate) author: Option<ForgeUser>,
+}
+
+/// A tag listed by the Gitea API.
+#[derive(Deserialize)]
+pub(crate) struct GiteaTag {
+    pub(crate) name: String,
+    /// The message of an annotated tag.
+    #[serde(default)]
+    pub(crate) message: Option<String>,
+}
+
+/// The author of a GitHub or Gitea release.
+#[derive(Deserialize)]
+pub(crate) struct ForgeUser {
+    pub(crate) login: String,
+    /// The display name, only reported by Gitea.
+    #[serde(default)]
+    pub(crate) full_name: Option<String>,
+    /// The kind of account, `Bot` for apps on GitHub.
+    #[serde(default, rename = "type")]
+    pub(crate) kind: Option<String>,
+}
+
+impl From<&ForgeUser> for ReleaseAuthor {
+    fn from(user: &ForgeUser) -> Self {
+        Self {
+            login: user.login.clone(),
+            name: user.full_name.clone().filter(|name| !name.is_empty()),
+            is_bot: user.kind.as_deref() == Some("Bot")
+                || user.login.ends_with("[bot]")
+                || user.login == "github-actions",
+        }
+    }
+}
+
+/// An asset attached to a GitHub or Gitea release.
+#[derive(Deserialize)]
+pub(crate) struct ReleaseAsset {
+    pub(crate) name: String,
+}
+
+impl GiteaHubResponse {
+    /// Parses the release's tag as a version, ignoring a leading `v`.
+    pub(crate) fn version(&self) -> Option<Version> {
+        parse_tag_version(&self.tag_name)
+    }
+
+    /// Returns when the release was published, if reported.
+    pub(crate) fn published_at(&self) -> Option<SystemTime> {
+        self.published_at
+            .as_deref()
+            .and_then(crate::build_age::parse_rfc3339)
+    }
+
+    /// Returns whether the release has no notes.
+    pub(crate) fn has_empty_body(&self) -> bool {
+        self.body
+            .as_deref()
+            .is_none_or(|body| body.trim().is_empty())
+    }
+
+    /// Returns the raw URL of `CHANGELOG.md` at the tag of the release.
+    ///
+    /// The repository URL is derived from the release page, so it works for
+    /// any GitHub or Gitea instance. `raw_path` is the path of raw files
+    /// below the repository, `raw` on GitHub and `raw/tag` on Gitea.
+    pub(crate) fn changelog_file_url(&self, raw_path: &str) -> Option<String> {
+        let repository = self
+            .html_url
+            .strip_suffix(&format!("/releases/tag/{}", self.tag_name))?;
+        Some(format!(
+            "{repository}/{raw_path}/{}/CHANGELOG.md",
+            self.tag_name
+        ))
+    }
+
+    /// Returns whether the release has an asset whose name matches the glob
+    /// `pattern`, ignoring case.
+    pub(crate) fn has_asset_matching(&self, pattern: &str) -> bool {
+        let pattern = pattern.to_lowercase();
+        self.assets
+            .iter()
+            .any(|asset| glob_match(&pattern, &asset.name.to_lowercase()))
+    }
+}
+
+/// Matches `text` against a glob pattern where `*` matches any run of
+/// characters and `?` matches a single character.
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // The position of the last `*` and the text position it was tried at.
+    let mut backtrack = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => {
+                let Some((star, start)) = backtrack else {
+                    return false;
+                };
+                p = star + 1;
+                t = start + 1;
+                backtrack = Some((star, start + 1));
+            }
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Parses a release tag or published version as semver, ignoring a leading
+/// `v`, so `v1.2.3` and `1.2.3` are the same version.
+pub(crate) fn parse_tag_version(tag: &str) -> Option<Version> {
+    Version::parse(tag.strip_prefix('v').unwrap_or(tag)).ok()
+}
+
+/// Parses a version as published by a package manager.
+///
+/// A leading `v` is ignored. Versions that are not valid semver are read
+/// from their numeric prefix: missing components are zero and components
+/// beyond the patch version are dropped, so `1.7` is `1.7.0` and
+/// `2.45.1.windows.1` is `2.45.1`. Returns `None` if the version does not
+/// start with a number.
+pub(crate) fn parse_loose_version(version: &str) -> Option<Version> {
+    let version = version.trim();
+    let version = version.strip_prefix('v').unwrap_or(version);
+    if let Ok(version) = Version::parse(version) {
+        return Some(version);
+    }
+    let mut numbers = version
+        .split(|c: char| !c.is_ascii_digit() && c != '.')
+        .next()?
+        .split('.')
+        .map(str::parse::<u64>);
+    let major = numbers.next()?.ok()?;
+    let minor = numbers.next().and_then(Result::ok).unwrap_or(0);
+    let patch = numbers.next().and_then(Result::ok).unwrap_or(0);
+    Some(Version::new(major, minor, patch))
+}
+
+/// Returns the upstream part of a distribution package version of the form
+/// `[epoch:]upstream-revision`, as used by Debian and Arch (`pkgver-pkgrel`).
+pub(crate) fn upstream_version(version: &str) -> &str {
+    let version = version
+        .split_once(':')
+        .map_or(version, |(_, version)| version);
+    version
+        .rsplit_once('-')
+        .map_or(version, |(upstream, _)| upstream)
 }
 
 /// Response structure for crates.io API calls.
@@ -24,6 +192,8 @@ pub(crate) struct GiteaHubResponse {
 pub(crate) struct CratesResponse {
     #[serde(rename = "crate")]
     pub(crate) info: CrateInfo,
+    #[serde(default)]
+    pub(crate) versions: Vec<CrateVersion>,
 }
 
 /// Crate information from crates.io.
@@ -31,6 +201,436 @@ pub(crate) struct CratesResponse {
 pub(crate) struct CrateInfo {
     pub(crate) max_version: Version,
     pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) repository: Option<String>,
+}
+
+/// A published version of a crate on crates.io.
+#[derive(Deserialize)]
+pub(crate) struct CrateVersion {
+    pub(crate) num: Version,
+    #[serde(default)]
+    pub(crate) yanked: bool,
+    /// When the version was published, in RFC 3339 format.
+    #[serde(default)]
+    pub(crate) created_at: Option<String>,
+}
+
+impl CratesResponse {
+    /// Creates a response from the file of a crate in a sparse index, which
+    /// holds one JSON object per published version.
+    ///
+    /// The index has no repository URL; publication times are read from the
+    /// `pubtime` field written by newer registries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a line is not a version entry or no version is
+    /// left after removing yanked ones.
+    pub(crate) fn from_index(index: &str) -> anyhow::Result<Self> {
+        let entries = index
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<IndexEntry>, _>>()
+            .map_err(|e| anyhow::anyhow!("Failed to parse sparse index entry: {e}"))?;
+        let latest = entries
+            .iter()
+            .filter(|entry| !entry.yanked)
+            .max_by(|a, b| a.vers.cmp(&b.vers))
+            .ok_or_else(|| anyhow::anyhow!("No versions found in sparse index"))?;
+        Ok(Self {
+            info: CrateInfo {
+                max_version: latest.vers.clone(),
+                name: latest.name.clone(),
+                repository: None,
+            },
+            versions: entries
+                .into_iter()
+                .map(|entry| CrateVersion {
+                    num: entry.vers,
+                    yanked: entry.yanked,
+                    created_at: entry.pubtime,
+                })
+                .collect(),
+        })
+    }
+}
+
+/// A published version of a crate in a sparse index.
+#[derive(Deserialize)]
+struct IndexEntry {
+    name: String,
+    vers: Version,
+    #[serde(default)]
+    yanked: bool,
+    /// When the version was published, in RFC 3339 format.
+    #[serde(default)]
+    pubtime: Option<String>,
+}
+
+/// A release description read from disk by [`crate::Source::File`].
+#[derive(Deserialize)]
+struct ReleaseManifest {
+    version: String,
+    notes: Option<String>,
+    url: Option<String>,
+}
+
+/// The account that published a release on GitHub or Gitea.
+///
+/// Lets security-conscious users display the provenance of a release.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ReleaseAuthor {
+    /// The login of the account, e.g. `github-actions[bot]`.
+    pub login: String,
+    /// The display name of the account, if the forge reports it.
+    pub name: Option<String>,
+    /// Whether the account is a bot, such as `github-actions[bot]`, i.e. the
+    /// release was created by CI.
+    pub is_bot: bool,
+}
+
+/// The latest release of a package, as reported by an [`crate::UpdateSource`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReleaseInfo {
+    /// The latest available version.
+    pub version: Version,
+    /// Optional changelog or release notes for the latest version.
+    pub changelog: Option<String>,
+    /// URL where more information can be found.
+    pub url: String,
+    /// All published versions, in any order, or empty if the source does not
+    /// list them.
+    ///
+    /// Used to fill [`UpdateInfo::versions_behind`].
+    pub versions: Vec<Version>,
+    /// A command that installs the latest version, if the source knows one.
+    ///
+    /// Used to fill [`UpdateInfo::upgrade_command`].
+    pub upgrade_command: Option<String>,
+    /// The documentation of the latest version, if the source knows it.
+    ///
+    /// Used to fill [`UpdateInfo::docs_url`].
+    pub docs_url: Option<String>,
+    /// The page of the package on lib.rs, if the source knows it.
+    ///
+    /// Used to fill [`UpdateInfo::lib_rs_url`].
+    pub lib_rs_url: Option<String>,
+    /// The source repository of the package, if the source knows it.
+    ///
+    /// Used to fill [`UpdateInfo::repository_url`].
+    pub repository_url: Option<String>,
+    /// A changelog file to read instead of missing release notes.
+    ///
+    /// Used to fill [`UpdateInfo::changelog_url`].
+    pub changelog_url: Option<String>,
+    /// When the latest version was published, if the source reports it.
+    ///
+    /// Used to fill [`UpdateInfo::published_at`].
+    pub published_at: Option<SystemTime>,
+    /// The account that published the latest version, if the source reports it.
+    ///
+    /// Used to fill [`UpdateInfo::author`].
+    pub author: Option<ReleaseAuthor>,
+    /// Whether the source marks the latest release as a pre-release.
+    ///
+    /// Used to fill [`UpdateInfo::is_prerelease`].
+    pub is_prerelease: bool,
+    /// Whether the latest release is an unpublished draft.
+    ///
+    /// Used to fill [`UpdateInfo::is_draft`].
+    pub is_draft: bool,
+}
+
+impl ReleaseInfo {
+    /// Creates a new `ReleaseInfo` without a changelog, version list,
+    /// upgrade command or links.
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The latest available version
+    /// * `url` - URL for more information about the release
+    #[must_use]
+    pub const fn new(version: Version, url: String) -> Self {
+        Self {
+            version,
+            changelog: None,
+            url,
+            versions: Vec::new(),
+            upgrade_command: None,
+            docs_url: None,
+            lib_rs_url: None,
+            repository_url: None,
+            changelog_url: None,
+            published_at: None,
+            author: None,
+            is_prerelease: false,
+            is_draft: false,
+        }
+    }
+
+    /// Creates a `ReleaseInfo` from a crates.io API response.
+    ///
+    /// Yanked versions are left out of the version list, the upgrade
+    /// command is `cargo install <name> --locked`, and the links point to
+    /// docs.rs, lib.rs and the repository of the crate.
+    pub(crate) fn from_crates(crates_response: CratesResponse) -> Self {
+        let name = &crates_response.info.name;
+        let version = crates_response.info.max_version;
+        let url = format!("https://crates.io/crates/{name}");
+        let upgrade_command = format!("cargo install {name} --locked");
+        let docs_url = format!("https://docs.rs/{name}/{version}");
+        let lib_rs_url = format!("https://lib.rs/crates/{name}");
+        let published_at = crates_response
+            .versions
+            .iter()
+            .find(|crate_version| crate_version.num == version)
+            .and_then(|crate_version| crate_version.created_at.as_deref())
+            .and_then(crate::build_age::parse_rfc3339);
+        Self {
+            versions: crates_response
+                .versions
+                .into_iter()
+                .filter(|version| !version.yanked)
+                .map(|version| version.num)
+                .collect(),
+            upgrade_command: Some(upgrade_command),
+            docs_url: Some(docs_url),
+            lib_rs_url: Some(lib_rs_url),
+            repository_url: crates_response
+                .info
+                .repository
+                .filter(|repository| !repository.is_empty()),
+            published_at,
+            ..Self::new(version, url)
+        }
+    }
+
+    /// Creates a `ReleaseInfo` from the tags of a Gitea repository without
+    /// releases.
+    ///
+    /// The highest stable version wins; pre-release tags are only reported if
+    /// there is no stable one. The message of an annotated tag becomes the
+    /// changelog.
+    ///
+    /// # Arguments
+    ///
+    /// * `tags` - The tags listed by the Gitea API
+    /// * `repository_url` - The web URL of the repository
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no tag is a version.
+    pub(crate) fn from_gitea_tags(tags: &[GiteaTag], repository_url: &str) -> anyhow::Result<Self> {
+        let versions: Vec<(Version, &GiteaTag)> = tags
+            .iter()
+            .filter_map(|tag| Some((parse_tag_version(&tag.name)?, tag)))
+            .collect();
+        let (version, tag) = versions
+            .iter()
+            .filter(|(version, _)| version.pre.is_empty())
+            .max_by(|a, b| a.0.cmp(&b.0))
+            .or_else(|| versions.iter().max_by(|a, b| a.0.cmp(&b.0)))
+            .ok_or_else(|| anyhow::anyhow!("No version tag found in {repository_url}"))?;
+        Ok(Self {
+            changelog: tag
+                .message
+                .as_deref()
+                .map(str::trim)
+                .filter(|message| !message.is_empty())
+                .map(str::to_owned),
+            is_prerelease: !version.pre.is_empty(),
+            versions: versions
+                .iter()
+                .map(|(version, _)| version.clone())
+                .collect(),
+            repository_url: Some(repository_url.to_owned()),
+            ..Self::new(
+                version.clone(),
+                format!("{repository_url}/src/tag/{}", tag.name),
+            )
+        })
+    }
+
+    /// Creates a `ReleaseInfo` from a GitHub or Gitea API response.
+    ///
+    /// # Arguments
+    ///
+    /// * `response` - The latest release
+    /// * `releases` - The releases list, used for the version list
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the version of the latest release cannot be parsed.
+    pub(crate) fn from_gitea_or_hub(
+        response: GiteaHubResponse,
+        releases: &[GiteaHubResponse],
+    ) -> anyhow::Result<Self> {
+        let published_at = response.published_at();
+        let author = response.author.as_ref().map(ReleaseAuthor::from);
+        let version = response.version().ok_or_else(|| {
+            anyhow::anyhow!("Failed to parse latest version: '{}'", response.tag_name)
+        })?;
+        Ok(Self {
+            changelog: response.body,
+            versions: releases
+                .iter()
+                .filter(|release| !release.draft)
+                .filter_map(GiteaHubResponse::version)
+                .collect(),
+            published_at,
+            author,
+            is_prerelease: response.prerelease,
+            is_draft: response.draft,
+            ..Self::new(version, response.html_url)
+        })
+    }
+}
+
+impl ReleaseInfo {
+    /// Extracts a `ReleaseInfo` from a JSON document using JSON pointers.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The JSON document returned by the endpoint
+    /// * `endpoint` - The endpoint URL, used if there is no `url_pointer`
+    /// * `version_pointer` - The pointer to the latest version
+    /// * `url_pointer` - The optional pointer to the URL for more information
+    /// * `notes_pointer` - The optional pointer to the release notes
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a pointer does not resolve to a string or the
+    /// version cannot be parsed.
+    pub(crate) fn from_json(
+        json: &serde_json::Value,
+        endpoint: &str,
+        version_pointer: &str,
+        url_pointer: Option<&str>,
+        notes_pointer: Option<&str>,
+    ) -> anyhow::Result<Self> {
+        let field = |pointer: &str| {
+            json.pointer(pointer)
+                .and_then(serde_json::Value::as_str)
+                .ok_or_else(|| anyhow::anyhow!("No string found at JSON pointer '{pointer}'"))
+        };
+        let version = field(version_pointer)?;
+        let version = parse_tag_version(version)
+            .ok_or_else(|| anyhow::anyhow!("Failed to parse latest version: '{version}'"))?;
+        let url = url_pointer.map_or(Ok(endpoint), field)?.to_owned();
+        let changelog = notes_pointer.map(field).transpose()?.map(str::to_owned);
+        Ok(Self {
+            changelog,
+            ..Self::new(version, url)
+        })
+    }
+
+    /// Reads a `ReleaseInfo` from a JSON or TOML release description.
+    ///
+    /// Files ending in `.toml` are parsed as TOML, all others as JSON. The
+    /// description has a `version` and optional `notes` and `url` fields; if
+    /// there is no `url`, the path is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed, or the version
+    /// cannot be parsed.
+    pub(crate) fn from_file(path: &Path) -> anyhow::Result<Self> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read {}: {e}", path.display()))?;
+        let manifest: ReleaseManifest = if path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("toml"))
+        {
+            toml::from_str(&content)
+                .map_err(|e| anyhow::anyhow!("Failed to parse {}: {e}", path.display()))?
+        } else {
+            serde_json::from_str(&content)
+                .map_err(|e| anyhow::anyhow!("Failed to parse {}: {e}", path.display()))?
+        };
+        let version = manifest.version.as_str();
+        let version = parse_tag_version(version)
+            .ok_or_else(|| anyhow::anyhow!("Failed to parse latest version: '{version}'"))?;
+        let url = manifest.url.unwrap_or_else(|| path.display().to_string());
+        Ok(Self {
+            changelog: manifest.notes,
+            ..Self::new(version, url)
+        })
+    }
+
+    /// Extracts a `ReleaseInfo` from a web page using a regular expression.
+    ///
+    /// Every match that parses as a version is collected, and the highest one
+    /// becomes the latest version. The first capture group is used if the
+    /// pattern has one, otherwise the whole match.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no version is found on the page.
+    #[cfg(feature = "scrape")]
+    pub(crate) fn from_page(page: &str, url: &str, pattern: &regex::Regex) -> anyhow::Result<Self> {
+        let versions: Vec<Version> = pattern
+            .captures_iter(page)
+            .filter_map(|captures| {
+                let version = captures.get(1).or_else(|| captures.get(0))?.as_str();
+                parse_tag_version(version)
+            })
+            .collect();
+        let version = versions
+            .iter()
+            .max()
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("No version matching the pattern found on {url}"))?;
+        Ok(Self {
+            versions,
+            ..Self::new(version, url.to_owned())
+        })
+    }
+}
+
+/// The severity of an update, derived from the semver delta between the
+/// current and the latest version.
+///
+/// The variants are ordered by severity, so `UpdateKind::Patch < UpdateKind::Major`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateKind {
+    /// The latest version is a pre-release, e.g. `1.2.0` to `1.3.0-rc.1`.
+    Prerelease,
+    /// Only the patch version changed, e.g. `1.2.0` to `1.2.1`.
+    Patch,
+    /// The minor version changed, e.g. `1.2.0` to `1.3.0`.
+    Minor,
+    /// The major version changed, e.g. `1.2.0` to `2.0.0`.
+    Major,
+}
+
+impl UpdateKind {
+    /// Classifies the update from `current` to `latest`.
+    ///
+    /// The kind is the first component that differs, and a pre-release
+    /// `latest` is always a [`UpdateKind::Prerelease`]. Moving from a
+    /// pre-release to the stable release of the same version, e.g.
+    /// `1.2.0-rc.1` to `1.2.0`, is a [`UpdateKind::Patch`].
+    ///
+    /// Returns `None` if `latest` is not newer than `current`, ignoring build
+    /// metadata.
+    pub(crate) fn classify(current: &Version, latest: &Version) -> Option<Self> {
+        if latest.cmp_precedence(current).is_le() {
+            return None;
+        }
+        Some(if !latest.pre.is_empty() {
+            Self::Prerelease
+        } else if latest.major != current.major {
+            Self::Major
+        } else if latest.minor != current.minor {
+            Self::Minor
+        } else {
+            Self::Patch
+        })
+    }
 }
 
 /// Contains information about available updates for a package.
@@ -38,15 +638,76 @@ pub(crate) struct CrateInfo {
 /// This structure provides all the necessary information about whether
 /// an update is available, including version details, changelog, and
 /// where to find more information.
+#[derive(Clone, Serialize)]
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "The flags are independent properties of a release"
+)]
 pub struct UpdateInfo {
+    /// The name of the package or repository that was checked.
+    pub name: String,
+    /// The currently installed version that was compared against.
+    pub current_version: Version,
     /// Whether a newer version is available than the current one.
+    ///
+    /// This is `false` if the update was suppressed by a [`crate::NotifyPolicy`],
+    /// if the latest release is a draft, or if it is marked as a pre-release
+    /// and the current version is stable.
     pub is_update_available: bool,
     /// The latest available version.
     pub latest_version: Version,
+    /// The severity of the update, or `None` if the latest version is not newer.
+    pub update_kind: Option<UpdateKind>,
     /// Optional changelog or release notes for the latest version.
     pub changelog: Option<String>,
     /// URL where more information can be found (crates.io, GitHub, etc.).
     pub url: String,
+    /// All versions newer than the current one up to and including the latest,
+    /// oldest first.
+    ///
+    /// Pre-releases are only included if the latest version is one. This is
+    /// empty if the source does not list its versions.
+    pub versions_behind: Vec<Version>,
+    /// A suggested command that installs the latest version, e.g.
+    /// `cargo install serde --locked` for crates.io.
+    ///
+    /// This is `None` if the source does not know how the package is installed.
+    pub upgrade_command: Option<String>,
+    /// The documentation of the latest version, e.g.
+    /// `https://docs.rs/serde/1.0.200` for crates.io.
+    pub docs_url: Option<String>,
+    /// The page of the package on lib.rs, e.g. `https://lib.rs/crates/serde`
+    /// for crates.io.
+    pub lib_rs_url: Option<String>,
+    /// The source repository of the package, if the source reports one.
+    pub repository_url: Option<String>,
+    /// The `CHANGELOG.md` of the repository at the released tag.
+    ///
+    /// Only set for GitHub and Gitea releases without notes, and only if an
+    /// update is available and the file exists, so users still have
+    /// somewhere to read what changed.
+    pub changelog_url: Option<String>,
+    /// When the latest version was published, if the source reports it
+    /// (crates.io, GitHub and Gitea).
+    ///
+    /// Serialized as seconds since the Unix epoch.
+    #[serde(serialize_with = "crate::build_age::serialize_unix_seconds")]
+    pub published_at: Option<SystemTime>,
+    /// The account that published the latest release, if the source reports
+    /// it (GitHub and Gitea).
+    pub author: Option<ReleaseAuthor>,
+    /// Whether the forge marks the latest release as a pre-release, even if
+    /// its version has no pre-release identifier.
+    ///
+    /// Pre-releases are not reported as updates of stable versions.
+    pub is_prerelease: bool,
+    /// Whether the latest release is an unpublished draft.
+    ///
+    /// Drafts are never reported as updates.
+    pub is_draft: bool,
+    /// Whether the changelog announces breaking changes, e.g. with
+    /// `BREAKING CHANGE` or a conventional-commit subject like `feat!: …`.
+    pub has_breaking_changes: bool,
 }
 
 impl UpdateInfo {
@@ -57,31 +718,42 @@ impl UpdateInfo {
     ///
     /// # Arguments
     ///
+    /// * `name` - The name of the package or repository
     /// * `latest_version` - The latest available version
     /// * `current_version` - The currently installed version
     /// * `changelog` - Optional changelog or release notes
     /// * `url` - URL for more information about the package
     pub(crate) fn new(
+        name: String,
         latest_version: Version,
         current_version: &Version,
         changelog: Option<String>,
         url: String,
     ) -> Self {
-        let is_update_available = (
-            latest_version.major,
-            latest_version.minor,
-            latest_version.patch,
-        ) > (
-            current_version.major,
-            current_version.minor,
-            current_version.patch,
-        );
+        let update_kind = UpdateKind::classify(current_version, &latest_version);
+        let has_breaking_changes = changelog
+            .as_deref()
+            .is_some_and(crate::notes::has_breaking_changes);
 
         Self {
-            is_update_available,
+            name,
+            current_version: current_version.clone(),
+            is_update_available: update_kind.is_some(),
             latest_version,
+            update_kind,
             changelog,
             url,
+            versions_behind: Vec::new(),
+            upgrade_command: None,
+            docs_url: None,
+            lib_rs_url: None,
+            repository_url: None,
+            changelog_url: None,
+            published_at: None,
+            author: None,
+            is_prerelease: false,
+            is_draft: false,
+            has_breaking_changes,
         }
     }
 
@@ -99,17 +771,56 @@ impl UpdateInfo {
         crates_response: CratesResponse,
         current_version: &str,
     ) -> anyhow::Result<Self> {
-        let latest_version = crates_response.info.max_version;
+        let name = crates_response.info.name.clone();
+        Self::from_release(
+            &name,
+            ReleaseInfo::from_crates(crates_response),
+            current_version,
+        )
+    }
+
+    /// Creates an `UpdateInfo` from the latest release reported by a source.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the package or repository
+    /// * `release` - The latest release
+    /// * `current_version` - The current version string to compare against
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the current version string cannot be parsed.
+    pub(crate) fn from_release(
+        name: &str,
+        release: ReleaseInfo,
+        current_version: &str,
+    ) -> anyhow::Result<Self> {
         let current_version = Version::parse(current_version)
-            .map_err(|e| anyhow::anyhow!("Failed to parse current version: {}", e))?;
-        let url = format!("https://crates.io/crates/{}", crates_response.info.name);
-        Ok(Self::new(latest_version, &current_version, None, url))
+            .map_err(|e| anyhow::anyhow!("Failed to parse current version: {e}"))?;
+        let mut info = Self::new(
+            name.to_owned(),
+            release.version,
+            &current_version,
+            release.changelog,
+            release.url,
+        );
+        info.set_versions_behind(release.versions);
+        info.upgrade_command = release.upgrade_command;
+        info.docs_url = release.docs_url;
+        info.lib_rs_url = release.lib_rs_url;
+        info.repository_url = release.repository_url;
+        info.changelog_url = release.changelog_url;
+        info.published_at = release.published_at;
+        info.author = release.author;
+        info.set_release_flags(release.is_prerelease, release.is_draft);
+        Ok(info)
     }
 
     /// Creates an `UpdateInfo` from a GitHub or Gitea API response.
     ///
     /// # Arguments
     ///
+    /// * `name` - The name of the repository
     /// * `response` - The response from the GitHub or Gitea API
     /// * `current_version` - The current version string to compare against
     ///
@@ -117,66 +828,349 @@ impl UpdateInfo {
     ///
     /// Returns an error if the version strings cannot be parsed.
     pub(crate) fn from_gitea_or_hub(
+        name: &str,
         response: GiteaHubResponse,
         current_version: &str,
     ) -> anyhow::Result<Self> {
-        let latest_version = response
-            .tag_name
-            .strip_prefix("v")
-            .unwrap_or(&response.tag_name);
-        let latest_version = Version::parse(latest_version)
-            .map_err(|e| anyhow::anyhow!("Failed to parse latest version: {}", e))?;
+        let latest_version = response.version().ok_or_else(|| {
+            anyhow::anyhow!("Failed to parse latest version: '{}'", response.tag_name)
+        })?;
         let current_version = Version::parse(current_version)
-            .map_err(|e| anyhow::anyhow!("Failed to parse current version: {}", e))?;
-        Ok(Self::new(
+            .map_err(|e| anyhow::anyhow!("Failed to parse current version: {e}"))?;
+        let published_at = response.published_at();
+        let author = response.author.as_ref().map(ReleaseAuthor::from);
+        let mut info = Self::new(
+            name.to_owned(),
             latest_version,
             &current_version,
             response.body,
             response.html_url,
-        ))
+        );
+        info.published_at = published_at;
+        info.author = author;
+        info.set_release_flags(response.prerelease, response.draft);
+        Ok(info)
+    }
+
+    /// Creates an `UpdateInfo` from the release with the highest version,
+    /// regardless of the order the releases were published in.
+    ///
+    /// Drafts are ignored, and so are pre-releases unless the current version
+    /// is a pre-release or no stable release qualifies. If `pattern` is set,
+    /// only releases with a matching asset are considered. The remaining
+    /// releases are applied as with [`UpdateInfo::apply_releases`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the repository
+    /// * `releases` - The releases listed by the GitHub or Gitea API
+    /// * `current_version` - The current version string to compare against
+    /// * `pattern` - The glob pattern an asset name must match, if any
+    /// * `changelog_url` - Returns the URL of the changelog of the latest
+    ///   release, if it has no notes; only called if an update is available
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no release qualifies or the version strings cannot
+    /// be parsed.
+    pub(crate) fn from_highest_release(
+        name: &str,
+        releases: Vec<GiteaHubResponse>,
+        current_version: &str,
+        pattern: Option<&str>,
+        changelog_url: impl FnOnce(&GiteaHubResponse) -> Option<String>,
+    ) -> anyhow::Result<Self> {
+        let current_version = Version::parse(current_version)
+            .map_err(|e| anyhow::anyhow!("Failed to parse current version: {e}"))?;
+        let candidates: Vec<(Version, usize)> = releases
+            .iter()
+            .enumerate()
+            .filter(|(_, release)| {
+                !release.draft && pattern.is_none_or(|pattern| release.has_asset_matching(pattern))
+            })
+            .filter_map(|(index, release)| Some((release.version()?, index)))
+            .collect();
+        let is_prerelease = |(version, index): &(Version, usize)| {
+            releases[*index].prerelease || !version.pre.is_empty()
+        };
+        let highest = |stable: bool| {
+            candidates
+                .iter()
+                .filter(|candidate| !(stable && is_prerelease(candidate)))
+                .max_by(|a, b| a.0.cmp(&b.0))
+        };
+        let latest = current_version
+            .pre
+            .is_empty()
+            .then(|| highest(true))
+            .flatten()
+            .or_else(|| highest(false))
+            .ok_or_else(|| {
+                pattern.map_or_else(
+                    || anyhow::anyhow!("No release of {name} has a valid version"),
+                    |pattern| {
+                        anyhow::anyhow!("No release of {name} has an asset matching '{pattern}'")
+                    },
+                )
+            })?;
+        let is_latest_prerelease = is_prerelease(latest);
+        let (latest_version, latest_index) = latest.clone();
+        let release = &releases[latest_index];
+        let mut info = Self::new(
+            name.to_owned(),
+            latest_version,
+            &current_version,
+            release.body.clone(),
+            release.html_url.clone(),
+        );
+        info.published_at = release.published_at();
+        info.author = release.author.as_ref().map(ReleaseAuthor::from);
+        info.set_release_flags(is_latest_prerelease, false);
+        if info.is_update_available {
+            info.changelog_url = changelog_url(release);
+        }
+        let releases: Vec<GiteaHubResponse> = releases
+            .into_iter()
+            .enumerate()
+            .filter(|(index, release)| {
+                *index == latest_index || (!release.draft && !release.prerelease)
+            })
+            .map(|(_, release)| release)
+            .collect();
+        info.apply_releases(&releases);
+        Ok(info)
+    }
+
+    /// Sets the pre-release and draft flags of the latest release.
+    ///
+    /// Drafts are never reported as updates, and pre-releases only if the
+    /// current version is a pre-release too.
+    fn set_release_flags(&mut self, is_prerelease: bool, is_draft: bool) {
+        self.is_prerelease = is_prerelease;
+        self.is_draft = is_draft;
+        if is_draft || (is_prerelease && self.current_version.pre.is_empty()) {
+            self.is_update_available = false;
+        }
+    }
+
+    /// Returns whether the version lies between the current and the latest version.
+    ///
+    /// Pre-releases are only counted if they are the latest version.
+    fn is_skipped(&self, version: &Version) -> bool {
+        *version > self.current_version
+            && *version <= self.latest_version
+            && (version.pre.is_empty() || *version == self.latest_version)
+    }
+
+    /// Sets the versions between the current and the latest version.
+    ///
+    /// # Arguments
+    ///
+    /// * `versions` - All versions published by the source, in any order
+    pub(crate) fn set_versions_behind(&mut self, versions: impl IntoIterator<Item = Version>) {
+        let mut versions_behind: Vec<Version> = versions
+            .into_iter()
+            .filter(|version| self.is_skipped(version))
+            .collect();
+        versions_behind.sort();
+        versions_behind.dedup();
+        self.versions_behind = versions_behind;
+    }
+
+    /// Applies the releases list of a GitHub or Gitea repository.
+    ///
+    /// Sets [`UpdateInfo::versions_behind`] and aggregates the changelog of
+    /// all skipped releases.
+    ///
+    /// # Arguments
+    ///
+    /// * `releases` - The releases listed by the GitHub or Gitea API
+    pub(crate) fn apply_releases(&mut self, releases: &[GiteaHubResponse]) {
+        self.set_versions_behind(
+            releases
+                .iter()
+                .filter(|release| !release.draft)
+                .filter_map(GiteaHubResponse::version),
+        );
+        self.aggregate_changelog(releases);
+    }
+
+    /// Replaces the changelog with the notes of all releases that were skipped.
+    ///
+    /// Collects every release newer than the current version and not newer
+    /// than the latest version, newest first, with a `## version` heading per
+    /// release. The changelog is left untouched if fewer than two releases
+    /// were skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `releases` - The releases listed by the GitHub or Gitea API
+    pub(crate) fn aggregate_changelog(&mut self, releases: &[GiteaHubResponse]) {
+        let mut skipped: Vec<(Version, &str)> = releases
+            .iter()
+            .filter(|release| !release.draft)
+            .filter_map(|release| {
+                let version = release.version()?;
+                self.is_skipped(&version)
+                    .then(|| (version, release.body.as_deref().unwrap_or_default()))
+            })
+            .collect();
+        if skipped.len() < 2 {
+            return;
+        }
+        skipped.sort_by(|a, b| b.0.cmp(&a.0));
+        let changelog = skipped
+            .iter()
+            .filter(|(_, body)| !body.trim().is_empty())
+            .map(|(version, body)| format!("## {version}\n{}", body.trim()))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        if !changelog.is_empty() {
+            self.has_breaking_changes = crate::notes::has_breaking_changes(&changelog);
+            self.changelog = Some(changelog);
+        }
+    }
+
+    /// Serializes the update information to a pretty-printed JSON string.
+    ///
+    /// The output matches the CLI's `--format json` mode, which reports the
+    /// `name` and `current_version` of the package next to the result; both
+    /// are fields of `UpdateInfo` so the output can be produced from it alone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serialization fails.
+    pub fn to_json_string(&self) -> anyhow::Result<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| anyhow::anyhow!("Failed to serialize update info to JSON: {e}"))
+    }
+
+    /// Serializes the update information to a TOML string.
+    ///
+    /// Fields without a value, such as a missing changelog, are omitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serialization fails.
+    pub fn to_toml_string(&self) -> anyhow::Result<String> {
+        toml::to_string(self)
+            .map_err(|e| anyhow::anyhow!("Failed to serialize update info to TOML: {e}"))
+    }
+
+    /// Renders the update information using a custom template.
+    ///
+    /// The following placeholders are replaced:
+    /// * `{name}` - The name of the package or repository
+    /// * `{current}` - The current version
+    /// * `{latest}` - The latest available version
+    /// * `{url}` - The URL for more information
+    /// * `{changelog}` - The changelog, or an empty string if there is none
+    ///
+    /// Unknown placeholders are kept as-is, and `{{` and `}}` render literal braces.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::check_crates_io;
+    ///
+    /// if let Ok(info) = check_crates_io("serde", "1.0.0") {
+    ///     println!("{}", info.format_with("{name} {current} -> {latest} ({url})"));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn format_with(&self, template: &str) -> String {
+        let mut output = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(index) = rest.find(['{', '}']) {
+            output.push_str(&rest[..index]);
+            rest = &rest[index..];
+            if let Some(after) = rest.strip_prefix("{{") {
+                output.push('{');
+                rest = after;
+            } else if let Some(after) = rest.strip_prefix("}}") {
+                output.push('}');
+                rest = after;
+            } else if let Some(after) = rest.strip_prefix('}') {
+                output.push('}');
+                rest = after;
+            } else if let Some(end) = rest.find('}') {
+                match &rest[1..end] {
+                    "name" => output.push_str(&self.name),
+                    "current" => output.push_str(&self.current_version.to_string()),
+                    "latest" => output.push_str(&self.latest_version.to_string()),
+                    "url" => output.push_str(&self.url),
+                    "changelog" => output.push_str(self.changelog.as_deref().unwrap_or_default()),
+                    _ => output.push_str(&rest[..=end]),
+                }
+                rest = &rest[end + 1..];
+            } else {
+                break;
+            }
+        }
+        output.push_str(rest);
+        output
     }
 
     /// Prints the update information if an update is available.
     ///
     /// This is a convenience method that only prints output when
-    /// `is_update_available` is true.
+    /// `is_update_available` is true. The notification goes to stderr unless
+    /// [`crate::set_output`] selected stdout. With the `color` feature
+    /// enabled, the output is styled unless `NO_COLOR` is set or the stream
+    /// is not a terminal;
+    /// the `markdown` feature additionally renders markdown in the changelog.
+    /// The info URL is rendered as a clickable hyperlink in terminals that
+    /// support it.
     pub fn print(&self) {
+        self.print_styled(crate::DisplayStyle::Emoji);
+    }
+
+    /// Writes the update information to the given writer if an update is available.
+    ///
+    /// Unlike [`UpdateInfo::print`], no color or hyperlink detection is
+    /// performed, since the writer is not necessarily a terminal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the writer fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::check_crates_io;
+    ///
+    /// if let Ok(info) = check_crates_io("serde", "1.0.0") {
+    ///     // Send the notification to stdout, whatever the output setting
+    ///     let _ = info.write_to(&mut std::io::stdout());
+    /// }
+    /// ```
+    pub fn write_to<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
         if self.is_update_available {
-            println!("{self}");
+            writeln!(w, "{self}")?;
         }
+        Ok(())
     }
-}
 
-impl fmt::Display for UpdateInfo {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// Prints the update information in the given style if an update is available.
+    ///
+    /// Color and hyperlink support are detected like in [`UpdateInfo::print`].
+    pub fn print_styled(&self, style: crate::DisplayStyle) {
         if self.is_update_available {
-            writeln!(f, "🚀  A new version is available!")?;
-            writeln!(f, "🔖  Latest version: {}", self.latest_version)?;
-            if let Some(changelog) = &self.changelog {
-                writeln!(f, "📝  Changelog:")?;
-                for line in changelog.lines().take(4) {
-                    if line.trim().is_empty() || line.starts_with("## ") {
-                        continue;
-                    }
-                    if line.starts_with('-') || line.starts_with('*') {
-                        writeln!(f, "    {line}")?;
-                    } else if line.starts_with("•") {
-                        writeln!(f, "    {}", line.trim_start_matches('•'))?;
-                    } else {
-                        writeln!(f, "    • {line}")?;
-                    }
-                }
-                if changelog
-                    .lines()
-                    .filter(|line| !line.trim().is_empty() && !line.starts_with("## "))
-                    .count()
-                    > 4
-                {
-                    writeln!(f, "    • (and more...)")?;
-                }
-            }
-            writeln!(f, "🌐  More info: {}", self.url)?;
+            crate::output().print(self.styled(style).for_output());
+        }
+    }
+
+    /// Prints the update information with the given verbosity if an update
+    /// is available.
+    ///
+    /// Color and hyperlink support are detected like in [`UpdateInfo::print`].
+    pub fn print_verbosity(&self, verbosity: crate::Verbosity) {
+        if self.is_update_available && verbosity != crate::Verbosity::Silent {
+            crate::output().print(
+                self.styled(crate::DisplayStyle::Emoji)
+                    .verbosity(verbosity)
+                    .for_output(),
+            );
         }
-        Ok(())
     }
 }
@@ -0,0 +1,56 @@
+use semver::Version;
+
+use crate::data::{ReleaseInfo, parse_loose_version, upstream_version};
+
+impl ReleaseInfo {
+    /// Creates a `ReleaseInfo` from the text output of `madison`.
+    ///
+    /// Each line has the form `package | version | suite | architectures`.
+    /// The upstream part of every version is collected, ignoring the epoch
+    /// and the Debian revision, and the highest one becomes the latest
+    /// version. The upgrade command is `sudo apt install --only-upgrade <name>`.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The output of `madison.php?package=<name>&s=<suite>&text=on`
+    /// * `name` - The source or binary package name
+    /// * `suite` - The queried suite, used in error messages
+    /// * `ubuntu` - Whether the Ubuntu archive was queried, used for the URL
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the package has no version in the suite.
+    pub(crate) fn from_madison(
+        text: &str,
+        name: &str,
+        suite: &str,
+        ubuntu: bool,
+    ) -> anyhow::Result<Self> {
+        let mut versions: Vec<Version> = text
+            .lines()
+            .filter_map(|line| {
+                let mut columns = line.split('|').map(str::trim);
+                let package = columns.next()?;
+                let version = columns.next()?;
+                (package == name).then_some(())?;
+                parse_loose_version(upstream_version(version))
+            })
+            .collect();
+        versions.sort();
+        versions.dedup();
+        let version = versions
+            .last()
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("{name} was not found in {suite}"))?;
+        let url = if ubuntu {
+            format!("https://launchpad.net/ubuntu/+source/{name}")
+        } else {
+            format!("https://tracker.debian.org/pkg/{name}")
+        };
+        Ok(Self {
+            versions,
+            upgrade_command: Some(format!("sudo apt install --only-upgrade {name}")),
+            ..Self::new(version, url)
+        })
+    }
+}
@@ -0,0 +1,848 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+use core::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::Deserialize;
+use unicode_width::{UnicodeWidthChar as _, UnicodeWidthStr as _};
+
+use crate::data::UpdateInfo;
+use crate::messages::Messages;
+
+/// The visual style used to render update information.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DisplayStyle {
+    /// Labels prefixed with emoji icons (the default).
+    #[default]
+    Emoji,
+    /// Plain ASCII labels, for terminals and logs that cannot render emoji.
+    Plain,
+    /// The notification centered inside a rounded box, like npm's
+    /// update-notifier.
+    Boxed,
+}
+
+/// How much of the notification is shown.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// Nothing is shown.
+    Silent,
+    /// A single line, like `serde 1.0.200 available (you have 1.0.0)`.
+    Minimal,
+    /// The full notification with changelog and links (the default).
+    #[default]
+    Full,
+}
+
+/// The bullet used for changelog lines.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BulletStyle {
+    /// Keep existing `-` and `*` markers and use the display style's bullet
+    /// for all other lines (the default).
+    #[default]
+    Auto,
+    /// Normalize every line to a `•` bullet.
+    Dot,
+    /// Normalize every line to a `-` bullet.
+    Dash,
+    /// Normalize every line to a `*` bullet.
+    Asterisk,
+    /// Strip existing markers and do not add a bullet.
+    None,
+}
+
+impl BulletStyle {
+    /// Returns the prefix used to normalize lines, or `None` for [`BulletStyle::Auto`].
+    const fn prefix(self) -> Option<&'static str> {
+        match self {
+            Self::Auto => None,
+            Self::Dot => Some("• "),
+            Self::Dash => Some("- "),
+            Self::Asterisk => Some("* "),
+            Self::None => Some(""),
+        }
+    }
+}
+
+/// Options controlling how the changelog is rendered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChangelogOptions {
+    /// The maximum number of changelog lines to show, or `None` to show all.
+    ///
+    /// `Some(0)` hides the changelog entirely. Defaults to `Some(4)`.
+    pub max_lines: Option<usize>,
+    /// Whether markdown headings (lines starting with `#`) are shown.
+    ///
+    /// Defaults to `false`.
+    pub include_headings: bool,
+    /// The bullet used for changelog lines.
+    pub bullet_style: BulletStyle,
+    /// Whether release notes written as conventional commits (`feat: ...`,
+    /// `fix(scope)!: ...`) are regrouped into "Breaking changes", "Features",
+    /// "Fixes" and "Other changes" sections.
+    ///
+    /// The section headings are shown even if `include_headings` is `false`.
+    /// Notes without conventional commits are shown as-is. Defaults to `false`.
+    pub group_commits: bool,
+}
+
+impl ChangelogOptions {
+    /// The default options, usable in `const` contexts.
+    const DEFAULT: Self = Self {
+        max_lines: Some(4),
+        include_headings: false,
+        bullet_style: BulletStyle::Auto,
+        group_commits: false,
+    };
+}
+
+impl Default for ChangelogOptions {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The labels and bullet used by a [`DisplayStyle`].
+struct Labels {
+    header: String,
+    latest: String,
+    arrow: &'static str,
+    changelog: String,
+    breaking: String,
+    upgrade: String,
+    more_info: String,
+    bullet: &'static str,
+}
+
+impl DisplayStyle {
+    /// Returns the labels used by this style with the given texts.
+    fn labels(self, messages: &Messages<'_>) -> Labels {
+        match self {
+            Self::Emoji | Self::Boxed => Labels {
+                header: format!("🚀  {}", messages.header),
+                latest: format!("🔖  {}", messages.latest_label),
+                arrow: "→",
+                changelog: format!("📝  {}", messages.changelog_label),
+                breaking: format!("⚠️  {}", messages.breaking_changes),
+                upgrade: format!("📦  {}", messages.upgrade_label),
+                more_info: format!("🌐  {}", messages.more_info_label),
+                bullet: "•",
+            },
+            Self::Plain => Labels {
+                header: messages.header.to_owned(),
+                latest: messages.latest_label.to_owned(),
+                arrow: "->",
+                changelog: messages.changelog_label.to_owned(),
+                breaking: format!("{}: {}", messages.warning, messages.breaking_changes),
+                upgrade: messages.upgrade_label.to_owned(),
+                more_info: messages.more_info_label.to_owned(),
+                bullet: "-",
+            },
+        }
+    }
+}
+
+/// Renders an [`UpdateInfo`] in a specific [`DisplayStyle`].
+///
+/// Created by [`UpdateInfo::styled`].
+pub struct UpdateDisplay<'a> {
+    info: &'a UpdateInfo,
+    style: DisplayStyle,
+    colored: bool,
+    hyperlink: bool,
+    markdown: bool,
+    changelog: ChangelogOptions,
+    messages: Messages<'a>,
+    width: Option<usize>,
+    verbosity: Verbosity,
+}
+
+impl UpdateInfo {
+    /// Returns a displayable view of the update information in the given style.
+    ///
+    /// The `Display` implementation of `UpdateInfo` itself uses
+    /// [`DisplayStyle::Emoji`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::{check_crates_io, DisplayStyle};
+    ///
+    /// if let Ok(info) = check_crates_io("serde", "1.0.0") {
+    ///     println!("{}", info.styled(DisplayStyle::Plain));
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn styled(&self, style: DisplayStyle) -> UpdateDisplay<'_> {
+        UpdateDisplay {
+            info: self,
+            style,
+            colored: false,
+            hyperlink: false,
+            markdown: false,
+            changelog: ChangelogOptions::DEFAULT,
+            messages: Messages::ENGLISH,
+            width: None,
+            verbosity: Verbosity::Full,
+        }
+    }
+}
+
+impl<'a> UpdateDisplay<'a> {
+    /// Enables or disables ANSI styling (bold version, dimmed URL).
+    ///
+    /// Use [`color_enabled`] to decide based on the environment.
+    #[cfg(feature = "color")]
+    #[must_use]
+    pub const fn colored(mut self, colored: bool) -> Self {
+        self.colored = colored;
+        self
+    }
+
+    /// Enables or disables rendering the info URL as a clickable OSC-8 hyperlink.
+    ///
+    /// Use [`hyperlinks_supported`] to decide based on the terminal.
+    #[must_use]
+    pub const fn hyperlink(mut self, hyperlink: bool) -> Self {
+        self.hyperlink = hyperlink;
+        self
+    }
+
+    /// Enables or disables rendering markdown in the changelog.
+    ///
+    /// When enabled, headings, bold and italic text, code spans and links are
+    /// rendered with ANSI styles instead of printing raw `**bold**` markers.
+    #[cfg(feature = "markdown")]
+    #[must_use]
+    pub const fn markdown(mut self, markdown: bool) -> Self {
+        self.markdown = markdown;
+        self
+    }
+
+    /// Sets the options used to render the changelog.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::{check_crates_io, ChangelogOptions, DisplayStyle};
+    ///
+    /// if let Ok(info) = check_crates_io("serde", "1.0.0") {
+    ///     let options = ChangelogOptions {
+    ///         max_lines: None,
+    ///         ..ChangelogOptions::default()
+    ///     };
+    ///     println!("{}", info.styled(DisplayStyle::Emoji).changelog(options));
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn changelog(mut self, options: ChangelogOptions) -> Self {
+        self.changelog = options;
+        self
+    }
+
+    /// Sets the texts of the notification, e.g. a translation or re-worded
+    /// labels.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::{check_crates_io, DisplayStyle, Messages};
+    ///
+    /// if let Ok(info) = check_crates_io("serde", "1.0.0") {
+    ///     println!("{}", info.styled(DisplayStyle::Emoji).messages(Messages::GERMAN));
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn messages(mut self, messages: Messages<'a>) -> Self {
+        self.messages = messages;
+        self
+    }
+
+    /// Wraps the notification to at most `width` columns, or not at all with
+    /// `None` (the default).
+    ///
+    /// Long changelog lines are wrapped at spaces with a hanging indent, and
+    /// a URL that does not fit after its label moves to its own line. The
+    /// width of wide characters like emoji is taken into account, and they
+    /// are never split. Use [`terminal_width`] to fit the terminal.
+    #[must_use]
+    pub const fn width(mut self, width: Option<usize>) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets how much of the notification is shown.
+    ///
+    /// With [`Verbosity::Minimal`], the notification is a single line like
+    /// `serde 1.0.200 available (you have 1.0.0)` in every style.
+    #[must_use]
+    pub const fn verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+
+    /// Applies the color, hyperlink and width settings detected for the
+    /// [`output`] stream.
+    pub(crate) fn for_output(self) -> Self {
+        #[cfg(feature = "color")]
+        let this = self.colored(color_enabled());
+        #[cfg(not(feature = "color"))]
+        let this = self;
+        #[cfg(feature = "markdown")]
+        let this = this.markdown(color_enabled());
+        this.hyperlink(hyperlinks_supported())
+            .width(terminal_width())
+    }
+
+    /// Wraps the value in the given ANSI escape code if styling is enabled.
+    fn paint<'v>(&self, code: &'static str, value: &'v dyn fmt::Display) -> Painted<'v> {
+        Painted {
+            code: self.colored.then_some(code),
+            value,
+        }
+    }
+}
+
+/// A value optionally wrapped in an ANSI escape code.
+struct Painted<'v> {
+    code: Option<&'static str>,
+    value: &'v dyn fmt::Display,
+}
+
+impl fmt::Display for Painted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.code {
+            Some(code) => write!(f, "\x1b[{code}m{}\x1b[0m", self.value),
+            None => self.value.fmt(f),
+        }
+    }
+}
+
+/// Returns the line reporting that `info` is the latest version, e.g.
+/// "✅  my-tool: You're already using the latest version! (1.2.0)".
+#[cfg(any(feature = "clap", feature = "cli"))]
+pub(crate) fn up_to_date_line(info: &UpdateInfo, messages: &Messages<'_>) -> String {
+    format!(
+        "✅  {}: {} ({})",
+        info.name, messages.up_to_date, info.latest_version
+    )
+}
+
+/// The stream the `print` functions write notifications to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Output {
+    /// Standard output.
+    Stdout,
+    /// Standard error (the default), so the notification never mixes with
+    /// the data a program writes to stdout, e.g. in `my-tool | jq`.
+    #[default]
+    Stderr,
+}
+
+impl Output {
+    /// Returns whether the stream is a terminal.
+    fn is_terminal(self) -> bool {
+        use std::io::IsTerminal as _;
+
+        match self {
+            Self::Stdout => std::io::stdout().is_terminal(),
+            Self::Stderr => std::io::stderr().is_terminal(),
+        }
+    }
+
+    /// Writes a notification followed by a newline to the stream.
+    ///
+    /// Nothing is written if a notification was already printed and
+    /// [`set_print_once`] is enabled.
+    pub(crate) fn print(self, notification: impl fmt::Display) {
+        if !claim_print() {
+            return;
+        }
+        match self {
+            Self::Stdout => println!("{notification}"),
+            Self::Stderr => eprintln!("{notification}"),
+        }
+    }
+
+    /// Writes a line to the stream, even if a notification was already
+    /// printed.
+    #[cfg(feature = "clap")]
+    pub(crate) fn print_line(self, line: impl fmt::Display) {
+        match self {
+            Self::Stdout => println!("{line}"),
+            Self::Stderr => eprintln!("{line}"),
+        }
+    }
+}
+
+/// Whether notifications are printed to stdout instead of stderr.
+static PRINT_TO_STDOUT: AtomicBool = AtomicBool::new(false);
+
+/// Sets the stream all `print` functions of this process write
+/// notifications to.
+///
+/// Notifications go to stderr by default. The writer-based functions like
+/// [`crate::print_check_to`] are not affected.
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::{Output, set_output};
+///
+/// set_output(Output::Stdout);
+/// # set_output(Output::Stderr);
+/// ```
+pub fn set_output(output: Output) {
+    PRINT_TO_STDOUT.store(output == Output::Stdout, Ordering::Relaxed);
+}
+
+/// Returns the stream notifications are printed to, set with [`set_output`].
+#[must_use]
+pub fn output() -> Output {
+    if PRINT_TO_STDOUT.load(Ordering::Relaxed) {
+        Output::Stdout
+    } else {
+        Output::Stderr
+    }
+}
+
+/// Whether at most one notification is printed per process.
+static PRINT_ONCE: AtomicBool = AtomicBool::new(false);
+
+/// Whether a notification was printed by this process.
+static PRINTED: AtomicBool = AtomicBool::new(false);
+
+/// Makes the `print` functions print at most one notification per process.
+///
+/// Useful if the update check is reachable from several code paths, e.g.
+/// both a CLI wrapper and the library it wraps call [`crate::print_check`].
+/// Once a notification was printed, later calls print nothing and skip the
+/// check, even if they run on other threads at the same time.
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::{print_check, set_print_once, Source};
+///
+/// set_print_once(true);
+/// print_check("serde", "1.0.0", Source::CratesIo);
+/// // Does not print the same notification again.
+/// print_check("serde", "1.0.0", Source::CratesIo);
+/// # set_print_once(false);
+/// ```
+pub fn set_print_once(enabled: bool) {
+    PRINT_ONCE.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns whether a `print` function printed a notification in this process.
+#[must_use]
+pub fn notification_printed() -> bool {
+    PRINTED.load(Ordering::Relaxed)
+}
+
+/// Returns whether printing is skipped because a notification was already
+/// printed and [`set_print_once`] is enabled.
+pub(crate) fn print_once_done() -> bool {
+    PRINT_ONCE.load(Ordering::Relaxed) && notification_printed()
+}
+
+/// Records that a notification is printed and returns whether it may be.
+///
+/// The swap makes sure only one of several concurrent callers gets to print
+/// if [`set_print_once`] is enabled.
+pub(crate) fn claim_print() -> bool {
+    !PRINTED.swap(true, Ordering::AcqRel) || !PRINT_ONCE.load(Ordering::Relaxed)
+}
+
+/// Returns whether the terminal attached to the [`output`] stream supports
+/// OSC-8 hyperlinks.
+///
+/// The `FORCE_HYPERLINK` environment variable overrides the detection (`0`
+/// disables, any other value enables). Otherwise, hyperlinks are only used if
+/// the stream is a terminal known to support them.
+#[must_use]
+pub fn hyperlinks_supported() -> bool {
+    use std::env;
+
+    if let Some(force) = env::var_os("FORCE_HYPERLINK") {
+        return force != "0";
+    }
+    if !output().is_terminal() {
+        return false;
+    }
+    let term_program = env::var("TERM_PROGRAM").unwrap_or_default();
+    let term = env::var("TERM").unwrap_or_default();
+    matches!(
+        term_program.as_str(),
+        "iTerm.app" | "WezTerm" | "vscode" | "Hyper" | "ghostty"
+    ) || ["kitty", "alacritty", "foot", "ghostty"]
+        .iter()
+        .any(|name| term.contains(name))
+        || env::var("VTE_VERSION")
+            .ok()
+            .and_then(|version| version.parse::<u32>().ok())
+            .is_some_and(|version| version >= 5000)
+        || env::var_os("WT_SESSION").is_some()
+        || env::var_os("KONSOLE_VERSION").is_some()
+}
+
+/// Returns the width of the terminal attached to the [`output`] stream in
+/// columns.
+///
+/// The `COLUMNS` environment variable takes precedence. Otherwise, the width
+/// is queried with `stty` on Unix. Returns `None` if the stream is not a
+/// terminal or the width is unknown.
+#[must_use]
+pub fn terminal_width() -> Option<usize> {
+    let columns = std::env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.parse().ok())
+        .filter(|&columns| columns > 0);
+    if columns.is_some() {
+        return columns;
+    }
+    if !output().is_terminal() {
+        return None;
+    }
+    #[cfg(unix)]
+    {
+        let tty = std::fs::File::open("/dev/tty").ok()?;
+        let output = std::process::Command::new("stty")
+            .arg("size")
+            .stdin(tty)
+            .stderr(std::process::Stdio::null())
+            .output()
+            .ok()?;
+        // `stty size` prints the rows and columns, e.g. `24 80`.
+        String::from_utf8(output.stdout)
+            .ok()?
+            .split_whitespace()
+            .nth(1)?
+            .parse()
+            .ok()
+            .filter(|&columns| columns > 0)
+    }
+    #[cfg(not(unix))]
+    None
+}
+
+/// Wraps text to lines of at most `width` columns, breaking at spaces.
+///
+/// Words wider than `width` are broken between characters. Zero-width
+/// characters such as variation selectors stay with the preceding character,
+/// and characters joined with a zero-width joiner are not separated, so
+/// emoji are never split. Text that fits is returned unchanged.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    if text.width() <= width {
+        return vec![text.to_owned()];
+    }
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut line_width = 0;
+    // The number of spaces before the next word, beyond the first one.
+    let mut spaces = 0;
+    for word in text.split(' ') {
+        if word.is_empty() {
+            spaces += 1;
+            continue;
+        }
+        let gap = 1 + core::mem::take(&mut spaces);
+        let word_width = word.width();
+        if line_width > 0 && line_width + gap + word_width <= width {
+            line.push_str(&" ".repeat(gap));
+            line.push_str(word);
+            line_width += gap + word_width;
+            continue;
+        }
+        if line_width > 0 {
+            lines.push(core::mem::take(&mut line));
+            line_width = 0;
+        }
+        let mut previous = None;
+        for c in word.chars() {
+            let char_width = c.width().unwrap_or(0);
+            if char_width > 0
+                && line_width > 0
+                && line_width + char_width > width
+                && previous != Some('\u{200d}')
+            {
+                lines.push(core::mem::take(&mut line));
+                line_width = 0;
+            }
+            line.push(c);
+            line_width += char_width;
+            previous = Some(c);
+        }
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Returns whether colored output should be used on the [`output`] stream.
+///
+/// Colors are disabled if the `NO_COLOR` environment variable is set to a
+/// non-empty value or if the stream is not a terminal.
+#[cfg(feature = "color")]
+#[must_use]
+pub fn color_enabled() -> bool {
+    std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty()) && output().is_terminal()
+}
+
+impl UpdateDisplay<'_> {
+    /// Renders the "More info" URL, honoring the color and hyperlink settings.
+    fn url(&self) -> String {
+        self.link(&self.info.url)
+    }
+
+    /// Renders text linking to the "More info" URL, e.g. a part of the URL.
+    fn link(&self, text: &str) -> String {
+        let text = self.paint("2", &text);
+        if self.hyperlink {
+            format!("\x1b]8;;{}\x1b\\{text}\x1b]8;;\x1b\\", self.info.url)
+        } else {
+            text.to_string()
+        }
+    }
+
+    /// Wraps text to the width, minus the columns taken by an indent.
+    fn wrap(&self, text: &str, indent: usize) -> Vec<String> {
+        self.width.map_or_else(
+            || vec![text.to_owned()],
+            |width| wrap(text, width.saturating_sub(indent)),
+        )
+    }
+
+    /// Writes a line starting with `first`, aligning wrapped lines below
+    /// the text and rendering its inline markdown.
+    fn write_wrapped(&self, f: &mut fmt::Formatter<'_>, first: &str, text: &str) -> fmt::Result {
+        let indent = first.width();
+        for (index, line) in self.wrap(text, indent).iter().enumerate() {
+            if index == 0 {
+                write!(f, "{first}")?;
+            } else {
+                write!(f, "{:indent$}", "")?;
+            }
+            writeln!(f, "{}", self.inline(line))?;
+        }
+        Ok(())
+    }
+
+    /// Renders the notification as a single line.
+    fn fmt_minimal(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let info = self.info;
+        writeln!(
+            f,
+            "{} {} {} ({} {})",
+            info.name,
+            self.paint("1", &info.latest_version),
+            self.messages.available,
+            self.messages.you_have,
+            info.current_version
+        )
+    }
+
+    /// Renders the notification as a list of labeled lines.
+    fn fmt_lines(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let info = self.info;
+        let labels = self.style.labels(&self.messages);
+        for line in self.wrap(&labels.header, 0) {
+            writeln!(f, "{line}")?;
+        }
+        write!(
+            f,
+            "{} {} {} {}",
+            labels.latest,
+            info.current_version,
+            labels.arrow,
+            self.paint("1", &info.latest_version)
+        )?;
+        if info.versions_behind.len() > 1 {
+            write!(
+                f,
+                " ({} {})",
+                info.versions_behind.len(),
+                self.messages.releases_behind
+            )?;
+        }
+        writeln!(f)?;
+        if info.has_breaking_changes {
+            for line in self.wrap(&labels.breaking, 0) {
+                writeln!(f, "{line}")?;
+            }
+        }
+        if self.changelog.max_lines != Some(0) {
+            if let Some(changelog) = &info.changelog {
+                writeln!(f, "{}", labels.changelog)?;
+                self.fmt_changelog(f, changelog)?;
+            } else if let Some(url) = &info.changelog_url {
+                writeln!(f, "{} {url}", labels.changelog)?;
+            }
+        }
+        if let Some(command) = &info.upgrade_command {
+            writeln!(f, "{} {command}", labels.upgrade)?;
+        }
+        if self
+            .width
+            .is_some_and(|width| labels.more_info.width() + 1 + info.url.width() > width)
+        {
+            writeln!(f, "{}", labels.more_info)?;
+            writeln!(f, "    {}", self.url())
+        } else {
+            writeln!(f, "{} {}", labels.more_info, self.url())
+        }
+    }
+
+    /// Renders the changelog lines according to the changelog options.
+    fn fmt_changelog(&self, f: &mut fmt::Formatter<'_>, changelog: &str) -> fmt::Result {
+        let options = &self.changelog;
+        let labels = self.style.labels(&self.messages);
+        let grouped = options
+            .group_commits
+            .then(|| crate::notes::group_conventional_commits(changelog))
+            .flatten();
+        let include_headings = options.include_headings || grouped.is_some();
+        let changelog = grouped.as_deref().unwrap_or(changelog);
+        let mut lines = changelog.lines().filter(|line| {
+            !line.trim().is_empty() && (include_headings || !line.trim_start().starts_with('#'))
+        });
+        let max_lines = options.max_lines.unwrap_or(usize::MAX);
+        for line in lines.by_ref().take(max_lines) {
+            if line.trim_start().starts_with('#') {
+                let heading = line.trim_start_matches(['#', ' ']);
+                if self.markdown {
+                    writeln!(f, "  \x1b[1m{}\x1b[22m", self.inline(heading))?;
+                } else {
+                    writeln!(f, "  {heading}")?;
+                }
+                continue;
+            }
+            let (prefix, text) = if let Some(prefix) = options.bullet_style.prefix() {
+                (prefix, line.trim_start_matches(['-', '*', '•', ' ']))
+            } else if let Some(text) = line.strip_prefix('-') {
+                ("-", text)
+            } else if let Some(text) = line.strip_prefix('*') {
+                ("*", text)
+            } else if line.starts_with("•") {
+                let text = line.trim_start_matches('•');
+                match self.style {
+                    DisplayStyle::Plain => ("-", text),
+                    DisplayStyle::Emoji | DisplayStyle::Boxed => ("", text),
+                }
+            } else {
+                self.write_wrapped(f, &format!("    {} ", labels.bullet), line)?;
+                continue;
+            };
+            // Keep the whitespace after the marker as-is, only style the text.
+            let trimmed = text.trim_start();
+            let spacing = &text[..text.len() - trimmed.len()];
+            self.write_wrapped(f, &format!("    {prefix}{spacing}"), trimmed)?;
+        }
+        if lines.next().is_some() {
+            match options.bullet_style.prefix() {
+                Some(prefix) => writeln!(f, "    {prefix}{}", self.messages.and_more)?,
+                None => writeln!(f, "    {} {}", labels.bullet, self.messages.and_more)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Renders inline markdown of a changelog line if enabled.
+    #[cfg_attr(
+        not(feature = "markdown"),
+        expect(clippy::unused_self, reason = "Markdown rendering is disabled")
+    )]
+    fn inline<'t>(&self, text: &'t str) -> std::borrow::Cow<'t, str> {
+        #[cfg(feature = "markdown")]
+        if self.markdown {
+            return crate::markdown::render_inline(text).into();
+        }
+        text.into()
+    }
+
+    /// Renders the notification centered inside a rounded box.
+    ///
+    /// The changelog is omitted to keep the banner compact.
+    fn fmt_boxed(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const PADDING: usize = 3;
+
+        let info = self.info;
+        let labels = self.style.labels(&self.messages);
+        let current = info.current_version.to_string();
+        let latest = info.latest_version.to_string();
+        // Each line is rendered with its display width, ignoring escape codes.
+        // Lines wider than the width minus the border and a space of padding
+        // on each side are wrapped.
+        let wrapped = |text: &str| {
+            self.wrap(text, 4)
+                .into_iter()
+                .map(|line| (line.width(), line))
+                .collect::<Vec<_>>()
+        };
+        let mut lines = wrapped(&labels.header);
+        lines.push((
+            labels.latest.width() + current.width() + labels.arrow.width() + latest.width() + 3,
+            format!(
+                "{} {current} {} {}",
+                labels.latest,
+                labels.arrow,
+                self.paint("1", &latest)
+            ),
+        ));
+        if info.has_breaking_changes {
+            lines.extend(wrapped(&labels.breaking));
+        }
+        if let Some(command) = &info.upgrade_command {
+            lines.extend(wrapped(&format!("{} {command}", labels.upgrade)));
+        }
+        let more_info_width = labels.more_info.width() + 1 + info.url.width();
+        if self.width.is_some_and(|width| more_info_width + 4 > width) {
+            lines.push((labels.more_info.width(), labels.more_info.clone()));
+            for part in self.wrap(&info.url, 4) {
+                lines.push((part.width(), self.link(&part)));
+            }
+        } else {
+            lines.push((
+                more_info_width,
+                format!("{} {}", labels.more_info, self.url()),
+            ));
+        }
+        let widest = lines.iter().map(|(width, _)| *width).max().unwrap_or(0);
+        let padding = self.width.map_or(PADDING, |width| {
+            (width.saturating_sub(widest + 2) / 2).clamp(1, PADDING)
+        });
+        let inner = widest + 2 * padding;
+
+        writeln!(f, "╭{}╮", "─".repeat(inner))?;
+        writeln!(f, "│{}│", " ".repeat(inner))?;
+        for (width, line) in &lines {
+            let left = (inner - width) / 2;
+            let right = inner - width - left;
+            writeln!(f, "│{}{line}{}│", " ".repeat(left), " ".repeat(right))?;
+        }
+        writeln!(f, "│{}│", " ".repeat(inner))?;
+        writeln!(f, "╰{}╯", "─".repeat(inner))
+    }
+}
+
+impl fmt::Display for UpdateDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.info.is_update_available {
+            return Ok(());
+        }
+        match (self.verbosity, self.style) {
+            (Verbosity::Silent, _) => Ok(()),
+            (Verbosity::Minimal, _) => self.fmt_minimal(f),
+            (Verbosity::Full, DisplayStyle::Emoji | DisplayStyle::Plain) => self.fmt_lines(f),
+            (Verbosity::Full, DisplayStyle::Boxed) => self.fmt_boxed(f),
+        }
+    }
+}
+
+impl fmt::Display for UpdateInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.styled(DisplayStyle::Emoji).fmt(f)
+    }
+}
@@ -0,0 +1,182 @@
+use quick_xml::{
+    Reader, XmlVersion, escape::resolve_predefined_entity, events::BytesStart, events::Event,
+};
+use semver::Version;
+
+use crate::{data::ReleaseInfo, sanitize::strip_markup};
+
+/// A single `<entry>` of an Atom feed or `<item>` of an RSS feed.
+#[derive(Default)]
+struct FeedEntry {
+    title: String,
+    link: String,
+    notes: String,
+}
+
+/// The element of an entry whose text is currently being read.
+#[derive(Clone, Copy)]
+enum Field {
+    Title,
+    Link,
+    Notes,
+}
+
+impl FeedEntry {
+    /// Returns the text buffer for the field.
+    const fn field_mut(&mut self, field: Field) -> &mut String {
+        match field {
+            Field::Title => &mut self.title,
+            Field::Link => &mut self.link,
+            Field::Notes => &mut self.notes,
+        }
+    }
+
+    /// Extracts the version from the link's last path segment or the title.
+    ///
+    /// GitHub's `releases.atom` links to `.../releases/tag/v1.2.0`, while
+    /// other feeds often only mention the version in the title.
+    fn version(&self) -> Option<Version> {
+        let tag = self.link.trim_end_matches('/').rsplit('/').next();
+        tag.and_then(find_version)
+            .or_else(|| find_version(&self.title))
+    }
+}
+
+/// Finds the first version in a text, e.g. `1.2.0` in `Release v1.2.0`.
+///
+/// Anything before the first digit of a word is ignored, so `v1.2.0` and
+/// `my-tool-1.2.0` are recognized as well.
+fn find_version(text: &str) -> Option<Version> {
+    text.split(|c: char| c.is_whitespace() || c == '/' || c == ':' || c == '_')
+        .find_map(|word| {
+            let start = word.find(|c: char| c.is_ascii_digit())?;
+            Version::parse(word[start..].trim_end_matches([',', ')'])).ok()
+        })
+}
+
+/// Returns the value of an `href` attribute of an Atom `<link>` element.
+///
+/// Links with a `rel` other than `alternate` (e.g. `enclosure`) are ignored.
+fn atom_href(element: &BytesStart<'_>) -> Option<String> {
+    let rel = element.try_get_attribute("rel").ok()?;
+    if rel.is_some_and(|rel| rel.value != "alternate") {
+        return None;
+    }
+    let href = element.try_get_attribute("href").ok()??;
+    Some(
+        href.normalized_value(XmlVersion::Implicit1_0)
+            .ok()?
+            .into_owned(),
+    )
+}
+
+/// Parses the entries of an Atom or RSS feed.
+#[expect(
+    clippy::wildcard_enum_match_arm,
+    reason = "Only entries and their title, link and notes are of interest"
+)]
+fn parse(xml: &str) -> anyhow::Result<Vec<FeedEntry>> {
+    let mut reader = Reader::from_str(xml);
+    let mut entries = Vec::new();
+    let mut entry: Option<FeedEntry> = None;
+    let mut field: Option<Field> = None;
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| anyhow::anyhow!("Failed to parse feed: {e}"))?;
+        match event {
+            Event::Start(element) => match element.local_name().as_ref() {
+                "entry" | "item" => entry = Some(FeedEntry::default()),
+                "title" => field = Some(Field::Title),
+                "content" | "summary" | "description" => field = Some(Field::Notes),
+                "link" => {
+                    if let Some(entry) = &mut entry
+                        && let Some(href) = atom_href(&element)
+                    {
+                        entry.link = href;
+                    } else {
+                        field = Some(Field::Link);
+                    }
+                }
+                _ => {}
+            },
+            Event::Empty(element) => {
+                if element.local_name().as_ref() == "link"
+                    && let Some(entry) = &mut entry
+                    && entry.link.is_empty()
+                    && let Some(href) = atom_href(&element)
+                {
+                    entry.link = href;
+                }
+            }
+            Event::Text(text) => {
+                if let (Some(entry), Some(field)) = (&mut entry, field) {
+                    entry.field_mut(field).push_str(&text.xml10_content());
+                }
+            }
+            Event::CData(text) => {
+                if let (Some(entry), Some(field)) = (&mut entry, field) {
+                    entry.field_mut(field).push_str(&text.xml10_content());
+                }
+            }
+            Event::GeneralRef(reference) => {
+                if let (Some(entry), Some(field)) = (&mut entry, field) {
+                    let buffer = entry.field_mut(field);
+                    if let Ok(Some(c)) = reference.resolve_char_ref() {
+                        buffer.push(c);
+                    } else if let Some(resolved) = resolve_predefined_entity(&reference) {
+                        buffer.push_str(resolved);
+                    }
+                }
+            }
+            Event::End(element) => match element.local_name().as_ref() {
+                "entry" | "item" => entries.extend(entry.take()),
+                _ => field = None,
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(entries)
+}
+
+impl ReleaseInfo {
+    /// Creates a `ReleaseInfo` from an Atom or RSS release feed.
+    ///
+    /// The entry with the highest version becomes the latest release. Its
+    /// notes are converted to plain text, since feeds usually contain HTML.
+    ///
+    /// # Arguments
+    ///
+    /// * `xml` - The feed document
+    /// * `url` - The feed URL, used if the entry has no link
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the feed cannot be parsed or no entry has a version.
+    pub(crate) fn from_feed(xml: &str, url: &str) -> anyhow::Result<Self> {
+        let entries = parse(xml)?;
+        let mut versions: Vec<(Version, &FeedEntry)> = entries
+            .iter()
+            .filter_map(|entry| Some((entry.version()?, entry)))
+            .collect();
+        versions.sort_by(|a, b| a.0.cmp(&b.0));
+        let (version, latest) = versions
+            .last()
+            .ok_or_else(|| anyhow::anyhow!("No release with a version found in {url}"))?;
+        let notes = strip_markup(&latest.notes);
+        let url = if latest.link.is_empty() {
+            url.to_owned()
+        } else {
+            latest.link.clone()
+        };
+        Ok(Self {
+            changelog: (!notes.is_empty()).then_some(notes),
+            versions: versions
+                .iter()
+                .map(|(version, _)| version.clone())
+                .collect(),
+            ..Self::new(version.clone(), url)
+        })
+    }
+}
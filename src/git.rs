@@ -0,0 +1,70 @@
+use semver::Version;
+
+use crate::data::{ReleaseInfo, parse_tag_version};
+
+/// Splits a git smart-HTTP response into its pkt-line payloads.
+///
+/// Each pkt-line starts with its length, including the 4 length bytes, as
+/// hexadecimal. Flush packets (`0000`) carry no payload and are skipped.
+fn pkt_lines(mut body: &[u8]) -> anyhow::Result<Vec<&[u8]>> {
+    let mut lines = Vec::new();
+    while !body.is_empty() {
+        let len = body
+            .get(..4)
+            .and_then(|len| std::str::from_utf8(len).ok())
+            .and_then(|len| usize::from_str_radix(len, 16).ok())
+            .ok_or_else(|| anyhow::anyhow!("Invalid pkt-line in git response"))?;
+        if len < 4 {
+            body = &body[4..];
+            continue;
+        }
+        let line = body
+            .get(4..len)
+            .ok_or_else(|| anyhow::anyhow!("Truncated pkt-line in git response"))?;
+        lines.push(line);
+        body = &body[len..];
+    }
+    Ok(lines)
+}
+
+impl ReleaseInfo {
+    /// Creates a `ReleaseInfo` from the tags advertised by a git server.
+    ///
+    /// The body is the response to `GET {url}/info/refs?service=git-upload-pack`.
+    /// Every tag that parses as a version (ignoring a leading `v`) is
+    /// collected, and the highest one becomes the latest version.
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - The ref advertisement
+    /// * `url` - The repository URL, used as the URL for more information
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the response is malformed or has no version tag.
+    pub(crate) fn from_git_refs(body: &[u8], url: &str) -> anyhow::Result<Self> {
+        let mut versions: Vec<Version> = pkt_lines(body)?
+            .into_iter()
+            .filter_map(|line| {
+                let line = std::str::from_utf8(line).ok()?;
+                // The first ref carries the capabilities after a NUL byte.
+                let line = line.split('\0').next()?.trim_end();
+                let (_, name) = line.split_once(' ')?;
+                let tag = name.strip_prefix("refs/tags/")?;
+                // Annotated tags are advertised twice, peeled with `^{}`.
+                let tag = tag.strip_suffix("^{}").unwrap_or(tag);
+                parse_tag_version(tag)
+            })
+            .collect();
+        versions.sort();
+        versions.dedup();
+        let version = versions
+            .last()
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("No version tag found in {url}"))?;
+        Ok(Self {
+            versions,
+            ..Self::new(version, url.to_owned())
+        })
+    }
+}
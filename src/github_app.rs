@@ -0,0 +1,198 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64::Engine as _;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use ring::rand::SystemRandom;
+use ring::signature::{RSA_PKCS1_SHA256, RsaKeyPair};
+use serde::Deserialize;
+
+/// How long before its expiry an installation token is replaced.
+const REFRESH_BEFORE_EXPIRY: Duration = Duration::from_mins(5);
+
+/// Authenticates as a GitHub App installation, for organizations that do not
+/// allow personal access tokens.
+///
+/// Signs a JWT with the private key of the app and exchanges it for an
+/// installation token, which is cached and renewed shortly before it expires
+/// after an hour. Clones share the cached token.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use update_available::{Checker, GithubApp, Source};
+///
+/// let key = std::fs::read_to_string("my-app.private-key.pem")?;
+/// let app = GithubApp::new("123456", 7_654_321, &key)?;
+/// let checker = Checker::new("my-tool", "1.0.0", Source::Github("my-org".into())).github_app(&app);
+///
+/// // The token also works for batched checks.
+/// let token = app.installation_token()?;
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+#[derive(Clone)]
+pub struct GithubApp {
+    app_id: String,
+    installation_id: u64,
+    key: Arc<RsaKeyPair>,
+    api_url: Option<String>,
+    token: Arc<Mutex<Option<InstallationToken>>>,
+}
+
+/// An installation token and when it expires.
+struct InstallationToken {
+    token: String,
+    expires_at: SystemTime,
+}
+
+/// The response of the installation token endpoint.
+#[derive(Deserialize)]
+struct AccessTokenResponse {
+    token: String,
+    expires_at: String,
+}
+
+impl GithubApp {
+    /// Creates an app authenticating as one of its installations.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_id` - The app ID or client ID of the app
+    /// * `installation_id` - The ID of the installation in the organization
+    /// * `private_key` - The PEM-encoded private key generated for the app
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the private key is not a PEM-encoded RSA key.
+    pub fn new(app_id: &str, installation_id: u64, private_key: &str) -> anyhow::Result<Self> {
+        Ok(Self {
+            app_id: app_id.to_owned(),
+            installation_id,
+            key: Arc::new(parse_private_key(private_key)?),
+            api_url: None,
+            token: Arc::default(),
+        })
+    }
+
+    /// Requests installation tokens from a GitHub Enterprise Server, e.g.
+    /// `https://github.example.com/api/v3`, instead of the GitHub API set
+    /// with `UPDATE_AVAILABLE_GITHUB_API_URL`.
+    #[must_use]
+    pub fn api_url(mut self, url: &str) -> Self {
+        self.api_url = Some(url.trim_end_matches('/').to_owned());
+        self
+    }
+
+    /// Returns an installation token, requesting a new one if there is none
+    /// or it expires within five minutes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JWT cannot be signed or GitHub refuses to
+    /// issue a token.
+    pub fn installation_token(&self) -> anyhow::Result<String> {
+        let mut cached = self.token.lock().unwrap_or_else(PoisonError::into_inner);
+        let now = SystemTime::now();
+        if let Some(token) = cached
+            .as_ref()
+            .filter(|token| now + REFRESH_BEFORE_EXPIRY < token.expires_at)
+        {
+            return Ok(token.token.clone());
+        }
+        // The lock is held while requesting, so concurrent checks wait for
+        // one new token instead of each requesting their own.
+        Ok(cached.insert(self.request_token(now)?).token.clone())
+    }
+
+    /// Exchanges a JWT for a new installation token.
+    fn request_token(&self, now: SystemTime) -> anyhow::Result<InstallationToken> {
+        let api_url = self
+            .api_url
+            .clone()
+            .unwrap_or_else(crate::config::github_api_url);
+        let mut response = crate::http::post(&format!(
+            "{api_url}/app/installations/{}/access_tokens",
+            self.installation_id
+        ))
+        .header("Authorization", &format!("Bearer {}", self.jwt(now)?))
+        .header("Accept", "application/vnd.github+json")
+        .send_empty()?;
+        if !response.status().is_success() {
+            anyhow::bail!(
+                "Failed to get an installation token from GitHub: {}",
+                response.status()
+            );
+        }
+        let response: AccessTokenResponse = response.body_mut().read_json()?;
+        Ok(InstallationToken {
+            token: response.token,
+            // Without a readable expiry, the token is renewed on next use.
+            expires_at: crate::build_age::parse_rfc3339(&response.expires_at).unwrap_or(now),
+        })
+    }
+
+    /// Returns a JWT identifying the app, valid for ten minutes from a
+    /// minute before `now` to allow for clock drift.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if signing fails.
+    pub(crate) fn jwt(&self, now: SystemTime) -> anyhow::Result<String> {
+        let now = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let header = URL_SAFE_NO_PAD.encode(r#"{"alg":"RS256","typ":"JWT"}"#);
+        let claims = URL_SAFE_NO_PAD.encode(
+            serde_json::json!({
+                "iat": now.saturating_sub(60),
+                "exp": now + 540,
+                "iss": self.app_id,
+            })
+            .to_string(),
+        );
+        let message = format!("{header}.{claims}");
+        let mut signature = vec![0; self.key.public().modulus_len()];
+        self.key
+            .sign(
+                &RSA_PKCS1_SHA256,
+                &SystemRandom::new(),
+                message.as_bytes(),
+                &mut signature,
+            )
+            .map_err(|_| anyhow::anyhow!("Failed to sign the GitHub App JWT"))?;
+        Ok(format!("{message}.{}", URL_SAFE_NO_PAD.encode(signature)))
+    }
+}
+
+impl core::fmt::Debug for GithubApp {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GithubApp")
+            .field("app_id", &self.app_id)
+            .field("installation_id", &self.installation_id)
+            .field("api_url", &self.api_url)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Parses a PEM-encoded RSA private key, in PKCS#1 form as downloaded from
+/// GitHub or in PKCS#8 form.
+pub(crate) fn parse_private_key(pem: &str) -> anyhow::Result<RsaKeyPair> {
+    let label = pem.lines().find_map(|line| {
+        line.trim()
+            .strip_prefix("-----BEGIN ")?
+            .strip_suffix("-----")
+    });
+    let body: String = pem
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with("-----"))
+        .collect();
+    let der = STANDARD
+        .decode(body)
+        .map_err(|e| anyhow::anyhow!("Failed to decode the private key: {e}"))?;
+    match label {
+        Some("RSA PRIVATE KEY") => RsaKeyPair::from_der(&der),
+        Some("PRIVATE KEY") => RsaKeyPair::from_pkcs8(&der),
+        _ => anyhow::bail!("Expected a PEM-encoded RSA private key"),
+    }
+    .map_err(|e| anyhow::anyhow!("Invalid private key: {e}"))
+}
@@ -0,0 +1,175 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+use core::fmt::Write as _;
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+use crate::data::{GiteaHubResponse, UpdateInfo};
+use crate::{PackageReport, UpdateAvailable, UpdateReport};
+
+/// The maximum number of repositories queried in one GraphQL request.
+const BATCH_SIZE: usize = 50;
+
+/// The GraphQL response for a batch of repositories.
+#[derive(Deserialize)]
+pub(crate) struct BatchResponse {
+    #[serde(default)]
+    data: Option<BTreeMap<String, Option<BatchRepository>>>,
+    #[serde(default)]
+    errors: Vec<BatchError>,
+}
+
+/// A repository in a batch response.
+#[derive(Deserialize)]
+struct BatchRepository {
+    #[serde(rename = "latestRelease")]
+    latest_release: Option<BatchRelease>,
+}
+
+/// The latest release of a repository in a batch response.
+#[derive(Deserialize)]
+struct BatchRelease {
+    #[serde(rename = "tagName")]
+    tag_name: String,
+    description: Option<String>,
+    #[serde(rename = "publishedAt")]
+    published_at: Option<String>,
+    url: String,
+}
+
+/// An error in a GraphQL response, e.g. for a repository that does not exist.
+#[derive(Deserialize)]
+struct BatchError {
+    message: String,
+    #[serde(default)]
+    path: Vec<serde_json::Value>,
+}
+
+/// Splits a `user/repo` string into its user and repository parts.
+pub(crate) fn split_repository(repository: &str) -> anyhow::Result<(&str, &str)> {
+    repository
+        .split_once('/')
+        .filter(|(user, name)| !user.is_empty() && !name.is_empty() && !name.contains('/'))
+        .ok_or_else(|| {
+            anyhow::anyhow!("Expected a repository in the form USER/REPO, got '{repository}'")
+        })
+}
+
+/// Builds a GraphQL query fetching the latest release of every repository,
+/// using the aliases `r0`, `r1`, … in the order of `repositories`.
+pub(crate) fn query(repositories: &[(&str, &str)]) -> String {
+    let mut query = String::from("query { ");
+    for (index, (user, name)) in repositories.iter().enumerate() {
+        // JSON strings are valid GraphQL string literals.
+        let _ = write!(
+            query,
+            "r{index}: repository(owner: {}, name: {}) {{ latestRelease {{ tagName description url publishedAt }} }} ",
+            serde_json::Value::from(*user),
+            serde_json::Value::from(*name),
+        );
+    }
+    query.push('}');
+    query
+}
+
+/// Converts a batch response into the update information of every repository.
+///
+/// # Arguments
+///
+/// * `response` - The GraphQL response
+/// * `repositories` - The `(user, repo, current_version)` triples of the
+///   batch, in the order used to build the query
+pub(crate) fn results(
+    response: BatchResponse,
+    repositories: &[(&str, &str, &str)],
+) -> Vec<anyhow::Result<UpdateInfo>> {
+    let mut data = response.data.unwrap_or_default();
+    repositories
+        .iter()
+        .enumerate()
+        .map(|(index, (user, name, current_version))| {
+            let alias = format!("r{index}");
+            let error = || {
+                response
+                    .errors
+                    .iter()
+                    .find(|error| {
+                        error.path.first().and_then(serde_json::Value::as_str) == Some(&alias)
+                    })
+                    .map_or_else(
+                        || format!("Repository {user}/{name} not found"),
+                        |error| error.message.clone(),
+                    )
+            };
+            let repository = data
+                .remove(&alias)
+                .flatten()
+                .ok_or_else(|| anyhow::anyhow!("{}", error()))?;
+            let release = repository
+                .latest_release
+                .ok_or_else(|| anyhow::anyhow!("{user}/{name} has no releases"))?;
+            let response = GiteaHubResponse {
+                tag_name: release.tag_name,
+                body: release.description,
+                html_url: release.url,
+                draft: false,
+                prerelease: false,
+                assets: Vec::new(),
+                published_at: release.published_at,
+                author: None,
+            };
+            let mut info = UpdateInfo::from_gitea_or_hub(name, response, current_version)?;
+            info.upgrade_command =
+                Some(UpdateAvailable::new(name, current_version).github_upgrade_command(user));
+            Ok(info)
+        })
+        .collect()
+}
+
+/// Checks the latest releases of many GitHub repositories with GraphQL.
+///
+/// # Errors
+///
+/// Returns an error if a request fails; errors of single repositories are
+/// returned per repository.
+pub(crate) fn check(repositories: &[(&str, &str)], token: &str) -> anyhow::Result<UpdateReport> {
+    let mut checked: Vec<Option<anyhow::Result<UpdateInfo>>> = Vec::new();
+    let mut valid = Vec::new();
+    for (index, (repository, current_version)) in repositories.iter().enumerate() {
+        match split_repository(repository) {
+            Ok((user, name)) => {
+                checked.push(None);
+                valid.push((index, (user, name, *current_version)));
+            }
+            Err(e) => checked.push(Some(Err(e))),
+        }
+    }
+    for batch in valid.chunks(BATCH_SIZE) {
+        let batch_repositories: Vec<(&str, &str, &str)> =
+            batch.iter().map(|(_, repository)| *repository).collect();
+        let names: Vec<(&str, &str)> = batch_repositories
+            .iter()
+            .map(|(user, name, _)| (*user, *name))
+            .collect();
+        let mut response =
+            crate::http::post(&format!("{}/graphql", crate::config::github_api_url()))
+                .header("Authorization", &format!("Bearer {token}"))
+                .send_json(serde_json::json!({ "query": query(&names) }))?;
+        if !response.status().is_success() {
+            anyhow::bail!("Failed to fetch data from GitHub: {}", response.status());
+        }
+        let response: BatchResponse = response.body_mut().read_json()?;
+        for ((index, _), result) in batch.iter().zip(results(response, &batch_repositories)) {
+            checked[*index] = Some(result);
+        }
+    }
+    Ok(repositories
+        .iter()
+        .zip(checked)
+        .map(|((repository, current_version), result)| PackageReport {
+            name: (*repository).to_owned(),
+            current_version: (*current_version).to_owned(),
+            result: result.unwrap_or_else(|| Err(anyhow::anyhow!("{repository} was not checked"))),
+        })
+        .collect())
+}
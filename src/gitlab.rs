@@ -0,0 +1,261 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+use core::fmt::Write as _;
+
+use semver::Version;
+use serde::Deserialize;
+
+use crate::data::{ReleaseAuthor, ReleaseInfo, parse_tag_version};
+
+/// A release listed by the GitLab releases API.
+#[derive(Deserialize)]
+pub(crate) struct GitlabRelease {
+    tag_name: String,
+    description: Option<String>,
+    released_at: Option<String>,
+    #[serde(default)]
+    upcoming_release: bool,
+    author: Option<GitlabAuthor>,
+    #[serde(rename = "_links")]
+    links: Option<GitlabLinks>,
+}
+
+/// The author of a GitLab release.
+#[derive(Deserialize)]
+struct GitlabAuthor {
+    username: String,
+    name: Option<String>,
+}
+
+/// The web links of a GitLab release.
+#[derive(Deserialize)]
+struct GitlabLinks {
+    #[serde(rename = "self")]
+    release: Option<String>,
+}
+
+/// A package listed by the GitLab packages API.
+#[derive(Deserialize)]
+pub(crate) struct GitlabPackage {
+    name: String,
+    version: String,
+    created_at: Option<String>,
+    status: Option<String>,
+    #[serde(rename = "_links")]
+    links: Option<PackageLinks>,
+}
+
+/// The web links of a GitLab package.
+#[derive(Deserialize)]
+struct PackageLinks {
+    web_path: Option<String>,
+}
+
+/// Returns the `:id` of a project in the GitLab API.
+///
+/// Numeric IDs are used as they are. Paths, including nested groups such as
+/// `group/subgroup/project`, are URL-encoded, so `/` becomes `%2F`. A
+/// trailing `/` or `.git` is ignored.
+///
+/// # Errors
+///
+/// Returns an error if the project is neither an ID nor a `namespace/project`
+/// path.
+pub(crate) fn project_id(project: &str) -> anyhow::Result<String> {
+    let project = project.trim().trim_matches('/');
+    let project = project.strip_suffix(".git").unwrap_or(project);
+    if !project.is_empty() && project.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Ok(project.to_owned());
+    }
+    if project.split('/').count() < 2 || project.split('/').any(str::is_empty) {
+        anyhow::bail!("Expected a GitLab project ID or NAMESPACE/PROJECT path, got '{project}'");
+    }
+    Ok(encode(project))
+}
+
+/// Percent-encodes everything but unreserved characters of a URL component.
+fn encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            let _ = write!(encoded, "%{byte:02X}");
+        }
+    }
+    encoded
+}
+
+/// Returns the web URL of a project, e.g. `https://gitlab.com/group/project`.
+///
+/// Projects given by ID use the `/projects/:id` URL, which GitLab redirects
+/// to the project.
+pub(crate) fn project_url(gitlab_url: &str, project: &str) -> String {
+    let gitlab_url = gitlab_url.trim_end_matches('/');
+    let project = project.trim().trim_matches('/');
+    let project = project.strip_suffix(".git").unwrap_or(project);
+    if project.bytes().all(|byte| byte.is_ascii_digit()) {
+        format!("{gitlab_url}/projects/{project}")
+    } else {
+        format!("{gitlab_url}/{project}")
+    }
+}
+
+/// Returns the API URL of the releases of a project, newest first.
+///
+/// # Errors
+///
+/// Returns an error if the project is neither an ID nor a path.
+pub(crate) fn releases_url(gitlab_url: &str, project: &str) -> anyhow::Result<String> {
+    Ok(format!(
+        "{}/api/v4/projects/{}/releases?order_by=released_at&sort=desc&per_page=20",
+        gitlab_url.trim_end_matches('/'),
+        project_id(project)?
+    ))
+}
+
+/// Returns the API URL of the generic packages named `name` in a project.
+///
+/// GitLab matches `package_name` by substring, so the packages still have to
+/// be filtered by name.
+///
+/// # Errors
+///
+/// Returns an error if the project is neither an ID nor a path.
+pub(crate) fn packages_url(gitlab_url: &str, project: &str, name: &str) -> anyhow::Result<String> {
+    Ok(format!(
+        "{}/api/v4/projects/{}/packages?package_type=generic&package_name={}&per_page=100",
+        gitlab_url.trim_end_matches('/'),
+        project_id(project)?,
+        encode(name)
+    ))
+}
+
+impl ReleaseInfo {
+    /// Creates a `ReleaseInfo` from the releases of a GitLab project.
+    ///
+    /// The releases are listed newest first; the newest one with a stable
+    /// version wins, and pre-releases are only reported if there is no
+    /// stable one. Upcoming releases, whose release date lies in the future,
+    /// are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `releases` - The releases listed by the GitLab API
+    /// * `project_url` - The web URL of the project
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no release is a version.
+    pub(crate) fn from_gitlab_releases(
+        releases: &[GitlabRelease],
+        project_url: &str,
+    ) -> anyhow::Result<Self> {
+        let versions: Vec<(Version, &GitlabRelease)> = releases
+            .iter()
+            .filter(|release| !release.upcoming_release)
+            .filter_map(|release| {
+                let tag = release.tag_name.as_str();
+                Some((parse_tag_version(tag)?, release))
+            })
+            .collect();
+        let (version, release) = versions
+            .iter()
+            .find(|(version, _)| version.pre.is_empty())
+            .or_else(|| versions.first())
+            .ok_or_else(|| anyhow::anyhow!("No versioned release found in {project_url}"))?;
+        let mut all_versions: Vec<Version> = versions
+            .iter()
+            .map(|(version, _)| version.clone())
+            .collect();
+        all_versions.sort();
+        all_versions.dedup();
+        let url = release
+            .links
+            .as_ref()
+            .and_then(|links| links.release.clone())
+            .unwrap_or_else(|| format!("{project_url}/-/releases/{}", release.tag_name));
+        Ok(Self {
+            changelog: release
+                .description
+                .as_deref()
+                .map(str::trim)
+                .filter(|description| !description.is_empty())
+                .map(str::to_owned),
+            is_prerelease: !version.pre.is_empty(),
+            versions: all_versions,
+            repository_url: Some(project_url.to_owned()),
+            published_at: release
+                .released_at
+                .as_deref()
+                .and_then(crate::build_age::parse_rfc3339),
+            author: release.author.as_ref().map(|author| ReleaseAuthor {
+                login: author.username.clone(),
+                name: author.name.clone(),
+                is_bot: author.username.ends_with("_bot") || author.username.contains("_bot_"),
+            }),
+            ..Self::new(version.clone(), url)
+        })
+    }
+
+    /// Creates a `ReleaseInfo` from the generic packages of a GitLab project.
+    ///
+    /// Only packages named exactly `name` count, and packages that are
+    /// hidden or still being processed are skipped. The highest version
+    /// (ignoring a leading `v`) becomes the latest version.
+    ///
+    /// # Arguments
+    ///
+    /// * `packages` - The packages listed by the GitLab API
+    /// * `name` - The name of the package
+    /// * `gitlab_url` - The URL of the GitLab instance
+    /// * `project_url` - The web URL of the project
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no package of that name has a version.
+    pub(crate) fn from_gitlab_packages(
+        packages: &[GitlabPackage],
+        name: &str,
+        gitlab_url: &str,
+        project_url: &str,
+    ) -> anyhow::Result<Self> {
+        let versions: Vec<(Version, &GitlabPackage)> = packages
+            .iter()
+            .filter(|package| {
+                package.name == name && package.status.as_deref().is_none_or(|s| s == "default")
+            })
+            .filter_map(|package| {
+                let version = package.version.as_str();
+                Some((parse_tag_version(version)?, package))
+            })
+            .collect();
+        let (version, package) = versions
+            .iter()
+            .max_by(|a, b| a.0.cmp(&b.0))
+            .ok_or_else(|| anyhow::anyhow!("No version of {name} found in {project_url}"))?;
+        let mut all_versions: Vec<Version> = versions
+            .iter()
+            .map(|(version, _)| version.clone())
+            .collect();
+        all_versions.sort();
+        all_versions.dedup();
+        let url = package
+            .links
+            .as_ref()
+            .and_then(|links| links.web_path.as_deref())
+            .map_or_else(
+                || format!("{project_url}/-/packages"),
+                |path| format!("{}{path}", gitlab_url.trim_end_matches('/')),
+            );
+        Ok(Self {
+            is_prerelease: !version.pre.is_empty(),
+            versions: all_versions,
+            repository_url: Some(project_url.to_owned()),
+            published_at: package
+                .created_at
+                .as_deref()
+                .and_then(crate::build_age::parse_rfc3339),
+            ..Self::new(version.clone(), url)
+        })
+    }
+}
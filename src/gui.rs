@@ -0,0 +1,106 @@
+use egui::{Align2, Context, RichText, Ui, Window};
+
+use crate::data::UpdateInfo;
+
+/// The title of the update window and toast.
+const TITLE: &str = "A new version is available!";
+
+impl UpdateInfo {
+    /// Shows the update information in an egui [`Ui`].
+    ///
+    /// Shows the latest version, the changelog, the upgrade command if known,
+    /// and a clickable link to the release. Nothing is shown if no update is
+    /// available. Use this to embed the notification in an existing panel;
+    /// [`UpdateInfo::egui_window`] and [`UpdateInfo::egui_toast`] wrap it in
+    /// a window.
+    pub fn egui_ui(&self, ui: &mut Ui) {
+        if !self.is_update_available {
+            return;
+        }
+        ui.horizontal(|ui| {
+            ui.label(format!("Latest version: {} →", self.current_version));
+            ui.label(RichText::new(self.latest_version.to_string()).strong());
+        });
+        if let Some(changelog) = &self.changelog {
+            for line in changelog
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            {
+                ui.label(format!(
+                    "• {}",
+                    line.trim_start_matches(['-', '*', '•', ' '])
+                ));
+            }
+        }
+        if let Some(command) = &self.upgrade_command {
+            ui.horizontal(|ui| {
+                ui.label("Update with:");
+                ui.code(command);
+            });
+        }
+        ui.hyperlink_to("More info", &self.url);
+    }
+
+    /// Shows the update information in a centered egui window.
+    ///
+    /// The window can be closed with its close button, which sets `open` to
+    /// `false`. Keep the flag in the application state and call this every
+    /// frame; nothing is shown once it is closed or if no update is available.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The egui context
+    /// * `open` - Whether the window is shown
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::check_crates_io;
+    ///
+    /// let info = check_crates_io("serde", "1.0.0").ok();
+    /// let mut open = true;
+    /// let ctx = egui::Context::default();
+    /// let output = ctx.run_ui(egui::RawInput::default(), |ui| {
+    ///     if let Some(info) = &info {
+    ///         info.egui_window(ui.ctx(), &mut open);
+    ///     }
+    /// });
+    /// # output.drop_without_applying_deltas();
+    /// ```
+    pub fn egui_window(&self, ctx: &Context, open: &mut bool) {
+        self.show_window(ctx, open, Align2::CENTER_CENTER);
+    }
+
+    /// Shows the update information as a toast in the bottom right corner.
+    ///
+    /// Behaves like [`UpdateInfo::egui_window`], but the window is anchored
+    /// to the corner of the screen so it does not cover the application.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The egui context
+    /// * `open` - Whether the toast is shown
+    pub fn egui_toast(&self, ctx: &Context, open: &mut bool) {
+        self.show_window(ctx, open, Align2::RIGHT_BOTTOM);
+    }
+
+    /// Shows the update information in a window anchored at `align`.
+    fn show_window(&self, ctx: &Context, open: &mut bool, align: Align2) {
+        if !self.is_update_available || !*open {
+            return;
+        }
+        let offset = if align == Align2::CENTER_CENTER {
+            [0.0, 0.0]
+        } else {
+            [-8.0, -8.0]
+        };
+        Window::new(TITLE)
+            .id(egui::Id::new(("update_available", &self.name)))
+            .anchor(align, offset)
+            .collapsible(false)
+            .resizable(false)
+            .open(open)
+            .show(ctx, |ui| self.egui_ui(ui));
+    }
+}
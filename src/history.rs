@@ -0,0 +1,178 @@
+use core::time::Duration;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+use crate::data::UpdateInfo;
+
+/// A local log of the latest versions observed for each package.
+///
+/// Records when each latest version was first seen, so applications can
+/// tell when a release appeared or show only what is new since the last
+/// run. The history is stored as a small JSON file at a path chosen by the
+/// application.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use update_available::{UpdateHistory, check_crates_io};
+///
+/// let mut history = UpdateHistory::load("update-history.json").unwrap();
+/// let info = check_crates_io("serde", "1.0.0").unwrap();
+/// if history.record(&info) {
+///     println!("New since your last run: {}", info.latest_version);
+/// }
+/// history.save().unwrap();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UpdateHistory {
+    path: PathBuf,
+    packages: BTreeMap<String, PackageHistory>,
+}
+
+/// The observations of a package, as stored in the history file.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct PackageHistory {
+    /// The time of the last observation, in seconds since the Unix epoch.
+    last_checked: u64,
+    /// Every latest version observed, in the order they were first seen.
+    versions: Vec<Observation>,
+}
+
+/// A version and when it was first observed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Observation {
+    version: Version,
+    /// In seconds since the Unix epoch.
+    first_seen: u64,
+}
+
+impl UpdateHistory {
+    /// Reads the history from a file, or starts an empty one if the file
+    /// does not exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid history.
+    pub fn load(path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let path = path.into();
+        let packages = match std::fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content)
+                .map_err(|e| anyhow::anyhow!("Failed to parse {}: {e}", path.display()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => anyhow::bail!("Failed to read {}: {e}", path.display()),
+        };
+        Ok(Self { path, packages })
+    }
+
+    /// Writes the history to its file, creating missing parent directories.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self) -> anyhow::Result<()> {
+        if let Some(parent) = self.path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| anyhow::anyhow!("Failed to create {}: {e}", parent.display()))?;
+        }
+        let content = serde_json::to_string_pretty(&self.packages)?;
+        std::fs::write(&self.path, content)
+            .map_err(|e| anyhow::anyhow!("Failed to write {}: {e}", self.path.display()))
+    }
+
+    /// Returns the path of the history file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Records the latest version of a check, observed now.
+    ///
+    /// Returns whether the version was not observed before, i.e. is new
+    /// since the last run.
+    pub fn record(&mut self, info: &UpdateInfo) -> bool {
+        self.record_at(info, SystemTime::now())
+    }
+
+    /// Records the latest version of a check, observed at `time`.
+    ///
+    /// Returns whether the version was not observed before.
+    pub fn record_at(&mut self, info: &UpdateInfo, time: SystemTime) -> bool {
+        let time = unix_seconds(time);
+        let package = self.packages.entry(info.name.clone()).or_default();
+        package.last_checked = time;
+        if package
+            .versions
+            .iter()
+            .any(|observation| observation.version == info.latest_version)
+        {
+            return false;
+        }
+        package.versions.push(Observation {
+            version: info.latest_version.clone(),
+            first_seen: time,
+        });
+        true
+    }
+
+    /// Returns when a version of a package was first observed, e.g. when 2.0
+    /// first appeared.
+    #[must_use]
+    pub fn first_seen(&self, name: &str, version: &Version) -> Option<SystemTime> {
+        self.packages
+            .get(name)?
+            .versions
+            .iter()
+            .find(|observation| observation.version == *version)
+            .map(|observation| system_time(observation.first_seen))
+    }
+
+    /// Returns when a package was last recorded.
+    #[must_use]
+    pub fn last_checked(&self, name: &str) -> Option<SystemTime> {
+        self.packages
+            .get(name)
+            .map(|package| system_time(package.last_checked))
+    }
+
+    /// Returns the versions of a package first observed after `time`.
+    #[must_use]
+    pub fn seen_since(&self, name: &str, time: SystemTime) -> Vec<&Version> {
+        let time = unix_seconds(time);
+        self.versions(name)
+            .filter(|observation| observation.first_seen > time)
+            .map(|observation| &observation.version)
+            .collect()
+    }
+
+    /// Returns every observed latest version of a package with the time it
+    /// was first seen, in the order they were observed.
+    pub fn observed(&self, name: &str) -> impl Iterator<Item = (&Version, SystemTime)> {
+        self.versions(name)
+            .map(|observation| (&observation.version, system_time(observation.first_seen)))
+    }
+
+    /// Returns the observations of a package.
+    fn versions(&self, name: &str) -> impl Iterator<Item = &Observation> {
+        self.packages
+            .get(name)
+            .into_iter()
+            .flat_map(|package| &package.versions)
+    }
+}
+
+/// Converts a time to seconds since the Unix epoch, clamping earlier times.
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+/// Converts seconds since the Unix epoch to a time.
+fn system_time(seconds: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(seconds)
+}
@@ -0,0 +1,68 @@
+use serde::Deserialize;
+
+use crate::data::{ReleaseInfo, parse_loose_version};
+
+/// Response structure for the Homebrew formula API (`/api/formula/{name}.json`).
+#[derive(Deserialize)]
+struct FormulaResponse {
+    versions: FormulaVersions,
+}
+
+/// The versions of a Homebrew formula.
+#[derive(Deserialize)]
+struct FormulaVersions {
+    stable: Option<String>,
+}
+
+/// Response structure for the Homebrew cask API (`/api/cask/{name}.json`).
+#[derive(Deserialize)]
+struct CaskResponse {
+    version: String,
+}
+
+impl ReleaseInfo {
+    /// Creates a `ReleaseInfo` from a Homebrew formula or cask API response.
+    ///
+    /// The stable version of a formula, or the version of a cask, becomes the
+    /// latest version. Cask versions may carry a build identifier after a
+    /// comma, e.g. `1.2.0,abc123`, which is ignored. The upgrade command is
+    /// `brew upgrade <name>` (with `--cask` for casks).
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The response of `https://formulae.brew.sh/api/{formula,cask}/{name}.json`
+    /// * `name` - The formula or cask name
+    /// * `cask` - Whether `json` describes a cask
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the response cannot be parsed or has no stable
+    /// version.
+    pub(crate) fn from_homebrew(json: &str, name: &str, cask: bool) -> anyhow::Result<Self> {
+        let parse_error =
+            |e: serde_json::Error| anyhow::anyhow!("Failed to parse Homebrew response: {e}");
+        let version = if cask {
+            serde_json::from_str::<CaskResponse>(json)
+                .map_err(parse_error)?
+                .version
+        } else {
+            serde_json::from_str::<FormulaResponse>(json)
+                .map_err(parse_error)?
+                .versions
+                .stable
+                .ok_or_else(|| anyhow::anyhow!("{name} has no stable version on Homebrew"))?
+        };
+        let version = version.split(',').next().unwrap_or_default();
+        let kind = if cask { "cask" } else { "formula" };
+        let version = parse_loose_version(version)
+            .ok_or_else(|| anyhow::anyhow!("Failed to parse latest version: '{version}'"))?;
+        Ok(Self {
+            upgrade_command: Some(if cask {
+                format!("brew upgrade --cask {name}")
+            } else {
+                format!("brew upgrade {name}")
+            }),
+            ..Self::new(version, format!("https://formulae.brew.sh/{kind}/{name}"))
+        })
+    }
+}
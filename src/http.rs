@@ -0,0 +1,755 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::Read;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::atomic::{AtomicBool, AtomicU16, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, LazyLock, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use ureq::config::Config;
+use ureq::http::{HeaderName, HeaderValue, Method, Request, Response, Uri, request, response};
+use ureq::middleware::MiddlewareNext;
+use ureq::typestate::{WithBody, WithoutBody};
+use ureq::unversioned::resolver::{DefaultResolver, ResolvedSocketAddrs, Resolver};
+use ureq::unversioned::transport::{DefaultConnector, NextTimeout};
+use ureq::{Agent, Body, BodyReader, RequestBuilder, SendBody};
+
+/// The User-Agent sent if the application does not set its own.
+const DEFAULT_USER_AGENT: &str = "update-available-lib";
+
+/// The compressions requested unless the source sets `Accept-Encoding`.
+///
+/// Gzip is decompressed by ureq and deflate by [`apply_config`].
+const ACCEPT_ENCODING: &str = "gzip, deflate";
+
+/// The most bytes read from a response unless the client or
+/// `UPDATE_AVAILABLE_MAX_BODY_SIZE` sets a limit.
+///
+/// Matches what ureq reads into memory at most, so the JSON and text helpers
+/// never fail before this limit.
+pub(crate) const DEFAULT_MAX_BODY_SIZE: u64 = 10 * 1024 * 1024;
+
+/// The redirects followed per request unless the client sets a limit.
+pub(crate) const DEFAULT_MAX_REDIRECTS: u32 = 5;
+
+/// The idle connections kept open per host.
+///
+/// Matches the default parallelism of batch checks, so checking many packages
+/// on one host reuses every connection instead of paying for a TLS handshake
+/// per request. HTTP/2 is not supported, so connections are not multiplexed.
+const MAX_IDLE_CONNECTIONS_PER_HOST: usize = crate::BatchLimits::DEFAULT_MAX_PARALLEL;
+
+/// The idle connections kept open across all hosts.
+const MAX_IDLE_CONNECTIONS: usize = 4 * MAX_IDLE_CONNECTIONS_PER_HOST;
+
+/// How long an idle connection is kept open for the next request.
+const MAX_IDLE_AGE: Duration = Duration::from_mins(1);
+
+/// The agent sending all requests, applying the settings of the current check.
+///
+/// Its connection pool is shared by all checks of the process. The timeout is
+/// read from `UPDATE_AVAILABLE_TIMEOUT` when the first request is sent.
+static AGENT: LazyLock<Agent> = LazyLock::new(|| {
+    Agent::with_parts(
+        Agent::config_builder()
+            .timeout_global(crate::config::timeout())
+            .max_idle_connections(MAX_IDLE_CONNECTIONS)
+            .max_idle_connections_per_host(MAX_IDLE_CONNECTIONS_PER_HOST)
+            .max_idle_age(MAX_IDLE_AGE)
+            // Redirects are followed by `apply_config`, so every hop goes
+            // through the redirect policy of the check.
+            .max_redirects(0)
+            .middleware(apply_config)
+            .build(),
+        DefaultConnector::default(),
+        OverrideResolver::default(),
+    )
+});
+
+/// A hook called with every request before it is sent.
+pub(crate) type BeforeRequest = dyn Fn(&mut request::Parts) -> anyhow::Result<()> + Send + Sync;
+
+/// A hook called with the metadata of every response.
+pub(crate) type AfterResponse = dyn Fn(&response::Parts) -> anyhow::Result<()> + Send + Sync;
+
+/// The settings applied to every request of a check.
+#[derive(Clone, Default)]
+pub(crate) struct RequestConfig {
+    /// The product of the application, e.g. `my-tool/1.2.0`.
+    pub(crate) user_agent: Option<String>,
+    /// Extra headers, replacing headers of the same name set by the source.
+    pub(crate) headers: Vec<(String, String)>,
+    /// The hooks called before every request, in order.
+    pub(crate) before_request: Vec<Arc<BeforeRequest>>,
+    /// The hooks called after every response, in order.
+    pub(crate) after_response: Vec<Arc<AfterResponse>>,
+    /// Collects the statistics of the requests, if metrics are recorded.
+    pub(crate) stats: Option<Arc<RequestStats>>,
+    /// Limits the requests in flight to a single host, if set.
+    pub(crate) host_limiter: Option<Arc<HostLimiter>>,
+    /// Delays requests to stay within the request budget of each host, if set.
+    pub(crate) rate_limiter: Option<Arc<RateLimiter>>,
+    /// The addresses used instead of a DNS lookup, by lowercase host name.
+    pub(crate) resolve: Option<Arc<HashMap<String, Vec<IpAddr>>>>,
+    /// The address families connected to, if set by the client.
+    pub(crate) ip_family: Option<crate::IpFamily>,
+    /// The base URLs replacing those of the built-in sources, if set.
+    pub(crate) endpoints: Option<Arc<crate::Endpoints>>,
+    /// The most bytes read from a response, if set by the client.
+    pub(crate) max_body_size: Option<u64>,
+    /// Whether self-hosted forges may be reached over plain HTTP, if set by
+    /// the client.
+    pub(crate) allow_http: Option<bool>,
+    /// The redirects followed per request, if set by the client.
+    pub(crate) max_redirects: Option<u32>,
+    /// Whether redirects to private, loopback and link-local addresses are
+    /// refused.
+    pub(crate) block_private_redirects: bool,
+    /// The redirects followed to reach the request being sent.
+    pub(crate) redirects: u32,
+    /// Whether a redirect left the origin of the first request, so the
+    /// extra headers are no longer sent.
+    pub(crate) cross_origin: bool,
+}
+
+/// Resolves hosts with the overrides of the current check, falling back to
+/// the system resolver, and orders the addresses by the IP family.
+#[derive(Debug, Default)]
+struct OverrideResolver(DefaultResolver);
+
+impl Resolver for OverrideResolver {
+    fn resolve(
+        &self,
+        uri: &ureq::http::Uri,
+        config: &Config,
+        timeout: NextTimeout,
+    ) -> Result<ResolvedSocketAddrs, ureq::Error> {
+        let current = current();
+        let family = current
+            .as_ref()
+            .and_then(|current| current.ip_family)
+            .or_else(crate::config::ip_family)
+            .unwrap_or_default();
+        let block_private = current
+            .as_ref()
+            .is_some_and(|current| current.redirects > 0 && current.block_private_redirects);
+        let overrides = current.and_then(|current| current.resolve.clone());
+        let addresses = uri.host().and_then(|host| {
+            overrides
+                .as_deref()?
+                .get(&host.to_ascii_lowercase())
+                .cloned()
+        });
+        let mut addresses: Vec<SocketAddr> = match addresses {
+            Some(addresses) => {
+                let port = uri.port_u16().unwrap_or_else(|| {
+                    if uri.scheme_str() == Some("http") {
+                        80
+                    } else {
+                        443
+                    }
+                });
+                addresses
+                    .into_iter()
+                    .map(|address| SocketAddr::new(address, port))
+                    .collect()
+            }
+            None => self.0.resolve(uri, config, timeout)?.to_vec(),
+        };
+        if block_private && addresses.iter().any(|address| is_private(address.ip())) {
+            // Checked again on connecting, as the address may differ from
+            // the one seen when the redirect was accepted.
+            return Err(private_redirect_error(uri));
+        }
+        addresses.retain(|address| family.allows(address.ip()));
+        // A stable sort keeps the order of the resolver within a family.
+        addresses.sort_by_key(|address| family.rank(address.ip()));
+        let mut resolved = self.empty();
+        for address in addresses {
+            if resolved.try_push(address).is_err() {
+                break;
+            }
+        }
+        if resolved.is_empty() {
+            Err(ureq::Error::HostNotFound)
+        } else {
+            Ok(resolved)
+        }
+    }
+}
+
+/// Limits the request rate to hosts with a token bucket per host.
+#[derive(Default)]
+pub(crate) struct RateLimiter {
+    buckets: Mutex<HashMap<String, TokenBucket>>,
+}
+
+impl RateLimiter {
+    /// Allows bursts of `requests` requests to `host`, refilled over `per`.
+    ///
+    /// Replaces the previous budget of the host.
+    pub(crate) fn set(&self, host: &str, requests: u32, per: Duration) {
+        let interval = per / requests.max(1);
+        self.buckets
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(
+                host.to_ascii_lowercase(),
+                TokenBucket {
+                    interval,
+                    burst: per.saturating_sub(interval),
+                    next: Instant::now(),
+                },
+            );
+    }
+
+    /// Waits until a request to `host` fits the budget of the host.
+    ///
+    /// Returns immediately for hosts without a budget.
+    pub(crate) fn wait(&self, host: &str) {
+        let delay = self
+            .buckets
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_mut(&host.to_ascii_lowercase())
+            .map_or(Duration::ZERO, |bucket| bucket.take(Instant::now()));
+        if !delay.is_zero() {
+            std::thread::sleep(delay);
+        }
+    }
+}
+
+/// A token bucket, tracked as the time it would be empty at, so it needs no
+/// refill timer.
+struct TokenBucket {
+    /// The time it takes to refill one token.
+    interval: Duration,
+    /// How far `next` may be ahead of now without waiting, i.e. the capacity
+    /// of the bucket minus one token.
+    burst: Duration,
+    /// The time at which all tokens taken so far are refilled.
+    next: Instant,
+}
+
+impl TokenBucket {
+    /// Takes a token, returning how long to wait until it is available.
+    fn take(&mut self, now: Instant) -> Duration {
+        let next = self.next.max(now);
+        self.next = next + self.interval;
+        next.saturating_duration_since(now)
+            .saturating_sub(self.burst)
+    }
+}
+
+/// Limits the number of requests in flight to each host.
+pub(crate) struct HostLimiter {
+    limit: usize,
+    active: Mutex<HashMap<String, usize>>,
+    released: Condvar,
+}
+
+impl HostLimiter {
+    /// Creates a limiter allowing `limit` requests per host, at least one.
+    pub(crate) fn new(limit: usize) -> Self {
+        Self {
+            limit: limit.max(1),
+            active: Mutex::new(HashMap::new()),
+            released: Condvar::new(),
+        }
+    }
+
+    /// Waits until a request to `host` may be sent.
+    ///
+    /// The request counts as in flight until the returned permit is dropped.
+    pub(crate) fn acquire(&self, host: &str) -> HostPermit<'_> {
+        let mut active = self.active.lock().unwrap_or_else(PoisonError::into_inner);
+        while active.get(host).is_some_and(|count| *count >= self.limit) {
+            active = self
+                .released
+                .wait(active)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        *active.entry(host.to_owned()).or_default() += 1;
+        drop(active);
+        HostPermit {
+            limiter: self,
+            host: host.to_owned(),
+        }
+    }
+}
+
+/// A request in flight to a host, released when dropped.
+pub(crate) struct HostPermit<'a> {
+    limiter: &'a HostLimiter,
+    host: String,
+}
+
+impl Drop for HostPermit<'_> {
+    fn drop(&mut self) {
+        let mut active = self
+            .limiter
+            .active
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(count) = active.get_mut(&self.host) {
+            *count = count.saturating_sub(1);
+        }
+        drop(active);
+        self.limiter.released.notify_all();
+    }
+}
+
+/// Statistics of the requests of a check.
+#[derive(Default)]
+pub(crate) struct RequestStats {
+    /// The status of the last response, or 0 if no response was received.
+    pub(crate) status: AtomicU16,
+    /// The number of response body bytes read.
+    pub(crate) bytes: AtomicU64,
+    /// Whether the last response was served by an HTTP cache.
+    pub(crate) cache_hit: AtomicBool,
+}
+
+/// Counts the bytes read from a response body.
+struct CountingReader {
+    inner: BodyReader<'static>,
+    stats: Arc<RequestStats>,
+}
+
+impl Read for CountingReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.stats
+            .bytes
+            .fetch_add(read.try_into().unwrap_or(u64::MAX), Ordering::Relaxed);
+        Ok(read)
+    }
+}
+
+/// Fails reading a response body once it exceeds a limit, so a misbehaving
+/// server cannot make a check allocate unbounded memory.
+struct LimitedReader {
+    inner: BodyReader<'static>,
+    remaining: u64,
+    limit: u64,
+}
+
+impl Read for LimitedReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        // Reads one byte past the limit to tell a body of exactly the limit
+        // from a longer one.
+        let max = usize::try_from(self.remaining.saturating_add(1)).unwrap_or(usize::MAX);
+        let len = buf.len().min(max);
+        let read = self.inner.read(buf.get_mut(..len).unwrap_or_default())?;
+        let read_bytes = read.try_into().unwrap_or(u64::MAX);
+        if read_bytes > self.remaining {
+            return Err(ureq::Error::BodyExceedsLimit(self.limit).into_io());
+        }
+        self.remaining -= read_bytes;
+        Ok(read)
+    }
+}
+
+thread_local! {
+    /// The settings of the check running on this thread.
+    static CONFIG: RefCell<Option<Arc<RequestConfig>>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `config` applied to every request made on this thread.
+pub(crate) fn with_config<T>(config: Option<Arc<RequestConfig>>, f: impl FnOnce() -> T) -> T {
+    let previous = CONFIG.with(|current| current.replace(config));
+    let result = f();
+    CONFIG.with(|current| current.replace(previous));
+    result
+}
+
+/// Runs `f` with `headers` added to the settings of the current check.
+pub(crate) fn with_headers<T>(headers: &[(String, String)], f: impl FnOnce() -> T) -> T {
+    let mut config = current()
+        .map(|config| (*config).clone())
+        .unwrap_or_default();
+    config.headers.extend_from_slice(headers);
+    with_config(Some(Arc::new(config)), f)
+}
+
+/// Returns the settings of the check running on this thread.
+///
+/// Pass them to [`with_config`] to keep them in spawned threads.
+pub(crate) fn current() -> Option<Arc<RequestConfig>> {
+    CONFIG.with(|current| current.borrow().clone())
+}
+
+/// Formats a `Basic` `Authorization` header value.
+pub(crate) fn basic_authorization(login: &str, password: &str) -> String {
+    use base64::Engine as _;
+
+    let encoded = base64::engine::general_purpose::STANDARD.encode(format!("{login}:{password}"));
+    format!("Basic {encoded}")
+}
+
+/// Formats the User-Agent, appending the library as a comment to the
+/// application's product, e.g. `my-tool/1.2.0 (update-available/0.1.2)`.
+pub(crate) fn format_user_agent(product: Option<&str>) -> String {
+    product.map_or_else(
+        || DEFAULT_USER_AGENT.to_owned(),
+        |product| format!("{product} (update-available/{})", env!("CARGO_PKG_VERSION")),
+    )
+}
+
+/// Sets the User-Agent, the extra headers of the check and the `.netrc`
+/// credentials of the host on a request.
+fn set_headers(
+    request: &mut Request<SendBody<'_>>,
+    config: &RequestConfig,
+) -> Result<(), ureq::Error> {
+    let user_agent = format_user_agent(config.user_agent.as_deref());
+    let headers = request.headers_mut();
+    headers.insert(
+        "User-Agent",
+        HeaderValue::try_from(user_agent).map_err(|e| ureq::Error::Http(e.into()))?,
+    );
+    if !headers.contains_key("Accept-Encoding") {
+        headers.insert("Accept-Encoding", HeaderValue::from_static(ACCEPT_ENCODING));
+    }
+    // The extra headers may hold credentials, which stay with their origin.
+    if !config.cross_origin {
+        for (name, value) in &config.headers {
+            headers.insert(
+                HeaderName::try_from(name).map_err(|e| ureq::Error::Http(e.into()))?,
+                HeaderValue::try_from(value).map_err(|e| ureq::Error::Http(e.into()))?,
+            );
+        }
+    }
+    if !headers.contains_key("Authorization")
+        && let Some(authorization) = request.uri().host().and_then(crate::netrc::authorization)
+    {
+        request.headers_mut().insert(
+            "Authorization",
+            HeaderValue::try_from(authorization).map_err(|e| ureq::Error::Http(e.into()))?,
+        );
+    }
+    Ok(())
+}
+
+/// Sets the User-Agent and the extra headers of the current check, calls its
+/// hooks and follows redirects.
+///
+/// Fails without sending the request if update checks were turned off with
+/// [`crate::disable`].
+///
+/// Runs on the thread sending the request, right before it is sent, so the
+/// extra headers replace the ones set by the source, and the hooks see the
+/// final request.
+fn apply_config(
+    mut request: Request<SendBody<'_>>,
+    next: MiddlewareNext<'_>,
+) -> Result<Response<Body>, ureq::Error> {
+    if crate::is_disabled() {
+        return Err(ureq::Error::Other("Update checks are disabled".into()));
+    }
+    let config = current().unwrap_or_default();
+    set_headers(&mut request, &config)?;
+    let (mut parts, body) = request.into_parts();
+    for hook in &config.before_request {
+        hook(&mut parts).map_err(|e| ureq::Error::Other(e.into()))?;
+    }
+    let request = Request::from_parts(parts, body);
+    if let Some(limiter) = &config.rate_limiter {
+        limiter.wait(request.uri().host().unwrap_or_default());
+    }
+    let permit = config.host_limiter.as_ref().map(|limiter| {
+        limiter.acquire(
+            request
+                .uri()
+                .authority()
+                .map_or("", ureq::http::uri::Authority::as_str),
+        )
+    });
+    let method = request.method().clone();
+    let uri = request.uri().clone();
+    let redirect_headers = request.headers().clone();
+    let response = next.handle(request);
+    drop(permit);
+    if let Some(stats) = &config.stats {
+        match &response {
+            Ok(response) => {
+                stats
+                    .status
+                    .store(response.status().as_u16(), Ordering::Relaxed);
+                stats
+                    .cache_hit
+                    .store(is_cache_hit(response.headers()), Ordering::Relaxed);
+            }
+            Err(ureq::Error::StatusCode(status)) => {
+                stats.status.store(*status, Ordering::Relaxed);
+            }
+            Err(_) => {}
+        }
+    }
+    let response = response?;
+    if let Some(location) = redirect_location(&method, &response)
+        && let Some(followed) = follow_redirect(&config, &uri, &location, method, redirect_headers)?
+    {
+        return Ok(followed);
+    }
+    let (mut parts, mut body) = response.into_parts();
+    for hook in &config.after_response {
+        hook(&parts).map_err(|e| ureq::Error::Other(e.into()))?;
+    }
+    let limit = config
+        .max_body_size
+        .or_else(crate::config::max_body_size)
+        .unwrap_or(DEFAULT_MAX_BODY_SIZE);
+    if body.content_length().is_some_and(|length| length > limit) {
+        return Err(ureq::Error::BodyExceedsLimit(limit));
+    }
+    if is_deflate(&parts.headers) {
+        body = inflate(body);
+        parts.headers.remove("Content-Encoding");
+        parts.headers.remove("Content-Length");
+    }
+    // Applied after decompression, so small compressed bodies cannot expand
+    // beyond the limit either.
+    body = with_mime_type(&body).reader(LimitedReader {
+        inner: body.into_reader(),
+        remaining: limit,
+        limit,
+    });
+    if let Some(stats) = &config.stats {
+        body = with_mime_type(&body).reader(CountingReader {
+            inner: body.into_reader(),
+            stats: Arc::clone(stats),
+        });
+    }
+    Ok(Response::from_parts(parts, body))
+}
+
+/// Starts a body builder keeping the MIME type of `body`, for wrapping its
+/// reader.
+fn with_mime_type(body: &Body) -> ureq::BodyBuilder {
+    let builder = Body::builder();
+    match body.mime_type() {
+        Some(mime_type) => builder.mime_type(mime_type),
+        None => builder,
+    }
+}
+
+/// Returns the `Location` of a redirect response to a `GET` or `HEAD`
+/// request.
+///
+/// Redirects of other methods are returned to the caller unfollowed.
+fn redirect_location(method: &Method, response: &Response<Body>) -> Option<String> {
+    if !matches!(response.status().as_u16(), 301 | 302 | 303 | 307 | 308)
+        || (method != Method::GET && method != Method::HEAD)
+    {
+        return None;
+    }
+    let location = response.headers().get("Location")?.to_str().ok()?;
+    Some(location.trim().to_owned())
+}
+
+/// Follows a redirect with the redirect policy of the check.
+///
+/// Returns `None` if redirects are turned off, so the redirect response is
+/// passed on. The request is sent again with all its headers if the redirect
+/// stays on the same scheme, host and port. Otherwise credentials, cookies and
+/// the extra headers of the check are dropped, so they are not leaked to
+/// another host.
+fn follow_redirect(
+    config: &RequestConfig,
+    from: &Uri,
+    location: &str,
+    method: Method,
+    mut headers: ureq::http::HeaderMap,
+) -> Result<Option<Response<Body>>, ureq::Error> {
+    let max_redirects = config.max_redirects.unwrap_or(DEFAULT_MAX_REDIRECTS);
+    if max_redirects == 0 {
+        return Ok(None);
+    }
+    if config.redirects >= max_redirects {
+        return Err(ureq::Error::TooManyRedirects);
+    }
+    let target = redirect_target(from, location)?;
+    if config.block_private_redirects && is_private_host(&target, config.resolve.as_deref()) {
+        return Err(private_redirect_error(&target));
+    }
+    let cross_origin = config.cross_origin || !same_origin(from, &target);
+    if cross_origin {
+        for name in ["Authorization", "Cookie", "Proxy-Authorization"] {
+            headers.remove(name);
+        }
+        for (name, _) in &config.headers {
+            headers.remove(name.as_str());
+        }
+    }
+    let mut request = Request::builder()
+        .method(method)
+        .uri(target)
+        .body(SendBody::none())
+        .map_err(ureq::Error::Http)?;
+    *request.headers_mut() = headers;
+    let mut hop = config.clone();
+    hop.redirects += 1;
+    hop.cross_origin = cross_origin;
+    with_config(Some(Arc::new(hop)), || AGENT.run(request)).map(Some)
+}
+
+/// Returns whether two URLs share their scheme, host and port.
+pub(crate) fn same_origin(a: &Uri, b: &Uri) -> bool {
+    let port = |uri: &Uri| {
+        let default = if uri.scheme_str() == Some("http") {
+            80
+        } else {
+            443
+        };
+        uri.port_u16().unwrap_or(default)
+    };
+    a.scheme_str() == b.scheme_str()
+        && a.host().map(str::to_ascii_lowercase) == b.host().map(str::to_ascii_lowercase)
+        && port(a) == port(b)
+}
+
+/// Resolves the `Location` of a redirect against the URL it was sent from.
+///
+/// # Errors
+///
+/// Returns an error if the location is invalid or downgrades an HTTPS
+/// request to another scheme.
+pub(crate) fn redirect_target(from: &Uri, location: &str) -> Result<Uri, ureq::Error> {
+    let scheme = from.scheme_str().unwrap_or("https");
+    let authority = from
+        .authority()
+        .map_or("", ureq::http::uri::Authority::as_str);
+    let target = if location.contains("://") {
+        location.to_owned()
+    } else if location.starts_with("//") {
+        format!("{scheme}:{location}")
+    } else if location.starts_with('/') {
+        format!("{scheme}://{authority}{location}")
+    } else {
+        let directory = from
+            .path()
+            .rsplit_once('/')
+            .map_or("", |(directory, _)| directory);
+        format!("{scheme}://{authority}{directory}/{location}")
+    };
+    let target: Uri = target
+        .parse()
+        .map_err(|e: ureq::http::uri::InvalidUri| ureq::Error::Http(e.into()))?;
+    if scheme.eq_ignore_ascii_case("https") && target.scheme_str() != Some("https") {
+        return Err(ureq::Error::Other(
+            format!("Refusing to follow a redirect from HTTPS to {target}").into(),
+        ));
+    }
+    Ok(target)
+}
+
+/// Returns whether the host of a URL is or resolves to a private address,
+/// honoring the host overrides of the check.
+///
+/// Hosts that cannot be resolved are left to fail on connecting.
+fn is_private_host(uri: &Uri, overrides: Option<&HashMap<String, Vec<IpAddr>>>) -> bool {
+    use std::net::ToSocketAddrs as _;
+
+    let Some(host) = uri.host() else {
+        return false;
+    };
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    if let Ok(address) = host.parse::<IpAddr>() {
+        return is_private(address);
+    }
+    if let Some(addresses) =
+        overrides.and_then(|overrides| overrides.get(&host.to_ascii_lowercase()))
+    {
+        return addresses.iter().copied().any(is_private);
+    }
+    (host, uri.port_u16().unwrap_or(443))
+        .to_socket_addrs()
+        .is_ok_and(|mut addresses| addresses.any(|address| is_private(address.ip())))
+}
+
+/// Returns whether an address is private, loopback, link-local or otherwise
+/// not publicly routable, such as the cloud metadata service at
+/// `169.254.169.254`.
+pub(crate) const fn is_private(address: IpAddr) -> bool {
+    match address.to_canonical() {
+        IpAddr::V4(address) => {
+            let [first, second, ..] = address.octets();
+            address.is_private()
+                || address.is_loopback()
+                || address.is_link_local()
+                || address.is_unspecified()
+                || address.is_broadcast()
+                // The shared address space of carrier-grade NAT.
+                || (first == 100 && second & 0xc0 == 64)
+        }
+        IpAddr::V6(address) => {
+            address.is_loopback()
+                || address.is_unspecified()
+                || address.is_unique_local()
+                || address.is_unicast_link_local()
+        }
+    }
+}
+
+/// The error of a redirect to a private address that was refused.
+fn private_redirect_error(uri: &Uri) -> ureq::Error {
+    ureq::Error::Other(
+        format!("Refusing to follow a redirect to the private address of {uri}").into(),
+    )
+}
+
+/// Returns whether a response body is compressed with deflate.
+fn is_deflate(headers: &ureq::http::HeaderMap) -> bool {
+    headers
+        .get("Content-Encoding")
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.trim().eq_ignore_ascii_case("deflate"))
+}
+
+/// Decompresses a deflate response body.
+///
+/// HTTP's deflate is a zlib stream, but some servers send raw deflate data,
+/// so the zlib header is only expected if the first bytes contain one.
+fn inflate(body: Body) -> Body {
+    use std::io::BufRead as _;
+
+    let builder = with_mime_type(&body);
+    let mut reader = std::io::BufReader::new(body.into_reader());
+    let is_zlib = reader.fill_buf().is_ok_and(|start| {
+        start.len() >= 2
+            && start[0] & 0x0f == 8
+            && (u16::from(start[0]) << 8 | u16::from(start[1])) % 31 == 0
+    });
+    if is_zlib {
+        builder.reader(flate2::bufread::ZlibDecoder::new(reader))
+    } else {
+        builder.reader(flate2::bufread::DeflateDecoder::new(reader))
+    }
+}
+
+/// Returns whether a response was served by an HTTP cache, such as a CDN,
+/// according to its `X-Cache` header (e.g. `Hit from cloudfront`).
+fn is_cache_hit(headers: &ureq::http::HeaderMap) -> bool {
+    headers
+        .get_all("X-Cache")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .any(|value| value.to_ascii_lowercase().contains("hit"))
+}
+
+/// Starts a GET request with the settings of the current check.
+pub(crate) fn get(url: &str) -> RequestBuilder<WithoutBody> {
+    AGENT.get(url)
+}
+
+/// Starts a HEAD request with the settings of the current check.
+pub(crate) fn head(url: &str) -> RequestBuilder<WithoutBody> {
+    AGENT.head(url)
+}
+
+/// Starts a POST request with the settings of the current check.
+pub(crate) fn post(url: &str) -> RequestBuilder<WithBody> {
+    AGENT.post(url)
+}
@@ -0,0 +1,100 @@
+use std::path::{Path, PathBuf};
+
+/// How the running binary was installed.
+///
+/// Detected with [`InstallMethod::detect`] from the location of the
+/// executable. Pass it to [`crate::Checker::install_method`] to tailor the
+/// suggested upgrade command, and use [`InstallMethod::allows_self_update`]
+/// to decide whether the binary may replace itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InstallMethod {
+    /// Installed with `cargo install` (under `~/.cargo/bin` or `$CARGO_HOME/bin`).
+    Cargo,
+    /// Installed with Homebrew (in a Homebrew cellar).
+    Homebrew,
+    /// Installed with Scoop (under `scoop/apps` or `scoop/shims`).
+    Scoop,
+    /// Installed by the system package manager (in `/usr/bin` or similar).
+    System,
+    /// The installation method could not be determined, e.g. for a manually
+    /// downloaded binary.
+    Unknown,
+}
+
+impl InstallMethod {
+    /// Detects how the running binary was installed.
+    ///
+    /// Symlinks are resolved, so a Homebrew binary linked into `/usr/local/bin`
+    /// is detected as [`InstallMethod::Homebrew`]. Returns
+    /// [`InstallMethod::Unknown`] if the location of the executable cannot be
+    /// determined.
+    #[must_use]
+    pub fn detect() -> Self {
+        let Ok(exe) = std::env::current_exe().and_then(std::fs::canonicalize) else {
+            return Self::Unknown;
+        };
+        let cargo_home = std::env::var_os("CARGO_HOME").map(PathBuf::from);
+        Self::from_path(&exe, cargo_home.as_deref())
+    }
+
+    /// Classifies the location of an executable.
+    pub(crate) fn from_path(exe: &Path, cargo_home: Option<&Path>) -> Self {
+        if cargo_home.is_some_and(|cargo_home| exe.starts_with(cargo_home.join("bin"))) {
+            return Self::Cargo;
+        }
+        let components: Vec<String> = exe
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().to_lowercase())
+            .collect();
+        let contains = |parent: &str, child: &str| {
+            components
+                .windows(2)
+                .any(|pair| pair[0] == parent && pair[1] == child)
+        };
+        if contains(".cargo", "bin") {
+            Self::Cargo
+        } else if components.iter().any(|component| component == "cellar")
+            || exe.starts_with("/opt/homebrew")
+            || exe.starts_with("/home/linuxbrew/.linuxbrew")
+        {
+            Self::Homebrew
+        } else if contains("scoop", "apps") || contains("scoop", "shims") {
+            Self::Scoop
+        } else if exe.parent().is_some_and(|dir| {
+            ["/usr/bin", "/usr/sbin", "/bin", "/sbin"]
+                .map(Path::new)
+                .contains(&dir)
+        }) {
+            Self::System
+        } else {
+            Self::Unknown
+        }
+    }
+
+    /// Returns the command that upgrades the package with this install method.
+    ///
+    /// Returns `None` for [`InstallMethod::System`], since the package
+    /// manager is unknown, and for [`InstallMethod::Unknown`].
+    #[must_use]
+    pub fn upgrade_command(self, name: &str) -> Option<String> {
+        match self {
+            Self::Cargo => Some(format!("cargo install {name} --locked")),
+            Self::Homebrew => Some(format!("brew upgrade {name}")),
+            Self::Scoop => Some(format!("scoop update {name}")),
+            Self::System | Self::Unknown => None,
+        }
+    }
+
+    /// Returns whether the binary may replace itself with a newer version.
+    ///
+    /// Binaries managed by cargo or a package manager should be upgraded
+    /// through it, so the package database stays in sync. Only binaries
+    /// installed in an unknown way, e.g. downloaded manually, may self-update.
+    #[must_use]
+    pub const fn allows_self_update(self) -> bool {
+        match self {
+            Self::Cargo | Self::Homebrew | Self::Scoop | Self::System => false,
+            Self::Unknown => true,
+        }
+    }
+}
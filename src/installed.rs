@@ -0,0 +1,132 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+use std::{collections::BTreeMap, path::PathBuf};
+
+use semver::Version;
+use serde::Deserialize;
+
+use crate::data::UpdateInfo;
+
+/// The `.crates2.json` source identifier of crates.io.
+const CRATES_IO_SOURCE: &str = "registry+https://github.com/rust-lang/crates.io-index";
+
+/// The subset of `~/.cargo/.crates2.json` needed for checking.
+#[derive(Deserialize)]
+struct Crates2 {
+    #[serde(default)]
+    installs: BTreeMap<String, Crates2Install>,
+}
+
+/// A single `cargo install`ed package in `.crates2.json`.
+#[derive(Deserialize)]
+struct Crates2Install {
+    #[serde(default)]
+    bins: Vec<String>,
+}
+
+/// A package installed from crates.io via `cargo install`.
+pub(crate) struct Installed {
+    pub(crate) name: String,
+    pub(crate) version: Version,
+    pub(crate) bins: Vec<String>,
+}
+
+/// The result of checking a single `cargo install`ed package.
+pub struct InstalledCrate {
+    /// The crates.io name of the package.
+    pub name: String,
+    /// The installed version.
+    pub current_version: Version,
+    /// The binaries installed by the package.
+    pub bins: Vec<String>,
+    /// The update information, or the error that occurred while checking.
+    pub result: anyhow::Result<UpdateInfo>,
+}
+
+/// The results of checking all `cargo install`ed packages.
+pub struct InstalledReport {
+    /// The results for each package installed from crates.io.
+    pub crates: Vec<InstalledCrate>,
+}
+
+impl InstalledReport {
+    /// Returns the installed packages for which an update is available.
+    pub fn outdated(&self) -> impl Iterator<Item = &InstalledCrate> {
+        self.crates.iter().filter(|installed| {
+            installed
+                .result
+                .as_ref()
+                .is_ok_and(|info| info.is_update_available)
+        })
+    }
+}
+
+/// Returns the path of `.crates2.json`, honoring `CARGO_HOME`.
+pub(crate) fn default_path() -> anyhow::Result<PathBuf> {
+    let cargo_home = match std::env::var_os("CARGO_HOME") {
+        Some(cargo_home) => PathBuf::from(cargo_home),
+        None => std::env::home_dir()
+            .ok_or_else(|| anyhow::anyhow!("Failed to determine the home directory"))?
+            .join(".cargo"),
+    };
+    Ok(cargo_home.join(".crates2.json"))
+}
+
+/// Parses the crates.io packages out of the contents of `.crates2.json`.
+///
+/// Keys have the form `name version (source)`; git and path installs are
+/// skipped since they cannot be compared against crates.io.
+///
+/// # Errors
+///
+/// Returns an error if the content is not valid `.crates2.json`.
+pub(crate) fn parse(content: &str) -> anyhow::Result<Vec<Installed>> {
+    let crates2: Crates2 = serde_json::from_str(content)
+        .map_err(|e| anyhow::anyhow!("Failed to parse .crates2.json: {e}"))?;
+    Ok(crates2
+        .installs
+        .into_iter()
+        .filter_map(|(key, install)| {
+            let mut parts = key.splitn(3, ' ');
+            let name = parts.next()?;
+            let version = Version::parse(parts.next()?).ok()?;
+            let source = parts.next()?.trim_start_matches('(').trim_end_matches(')');
+            (source == CRATES_IO_SOURCE).then(|| Installed {
+                name: name.to_owned(),
+                version,
+                bins: install.bins,
+            })
+        })
+        .collect())
+}
+
+/// Checks every package listed in the given `.crates2.json` for updates.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or parsed.
+#[cfg(feature = "blocking")]
+pub(crate) fn check(path: &std::path::Path) -> anyhow::Result<InstalledReport> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read {}: {e}", path.display()))?;
+    let installed = parse(&content)?;
+    let packages: Vec<(String, String)> = installed
+        .iter()
+        .map(|package| (package.name.clone(), package.version.to_string()))
+        .collect();
+    let results = crate::logic::check_many(
+        &packages,
+        &crate::Source::CratesIo,
+        crate::BatchLimits::default(),
+    );
+    let crates = installed
+        .into_iter()
+        .zip(results)
+        .map(|(package, result)| InstalledCrate {
+            name: package.name,
+            current_version: package.version,
+            bins: package.bins,
+            result,
+        })
+        .collect();
+    Ok(InstalledReport { crates })
+}
@@ -1,7 +1,114 @@
-use crate::data::{UpdateAvailable, UpdateInfo};
+#![cfg_attr(
+    not(feature = "blocking"),
+    expect(
+        dead_code,
+        reason = "Most sources and parsers are only used by the blocking client"
+    )
+)]
+use std::path::PathBuf;
 
+pub use crate::build_age::parse_build_time;
+pub use crate::checker::{
+    CheckMetrics, CheckOutcome, Checker, NO_UPDATE_CHECK, SkipReason, disable, enable, is_disabled,
+};
+#[cfg(feature = "clap")]
+pub use crate::clap_support::{
+    CHECK_UPDATE, handle_check_update, with_check_update, with_check_update_subcommand,
+};
+#[cfg(feature = "blocking")]
+pub use crate::client::{IpFamily, UpdateClient};
+pub use crate::commits::CommitInfo;
+pub use crate::config::{CheckerConfig, Endpoints};
+use crate::data::UpdateAvailable;
+pub use crate::data::{ReleaseAuthor, ReleaseInfo, UpdateInfo, UpdateKind};
+#[cfg(feature = "color")]
+pub use crate::display::color_enabled;
+pub use crate::display::{
+    BulletStyle, ChangelogOptions, DisplayStyle, Output, UpdateDisplay, Verbosity,
+    hyperlinks_supported, notification_printed, output, set_output, set_print_once, terminal_width,
+};
+#[cfg(feature = "github-app")]
+pub use crate::github_app::GithubApp;
+pub use crate::history::UpdateHistory;
+pub use crate::install_method::InstallMethod;
+pub use crate::installed::{InstalledCrate, InstalledReport};
+pub use crate::messages::Messages;
+pub use crate::policy::NotifyPolicy;
+pub use crate::report::{BatchLimits, PackageReport, UpdateReport};
+pub use crate::sanitize::strip_markup;
+pub use crate::scan::{DependencyReport, MemberReport, WorkspaceReport};
+pub use crate::source::{SourcesReport, UpdateSource};
+pub use crate::tauri::{TauriManifest, TauriPlatform};
+#[cfg(feature = "keyring")]
+pub use crate::token_store::TokenStore;
+#[cfg(feature = "ratatui")]
+pub use crate::tui::UpdateBanner;
+#[cfg(feature = "webhook")]
+pub use crate::webhook::{SIGNATURE_HEADER, Webhook, WebhookFormat};
+
+mod aur;
+mod build_age;
+mod chat;
+mod checker;
+#[cfg(feature = "clap")]
+mod clap_support;
+#[cfg(feature = "cli")]
+pub mod cli;
+#[cfg(feature = "blocking")]
+mod client;
+mod commits;
+mod config;
 mod data;
+mod debian;
+mod display;
+#[cfg(feature = "feed")]
+mod feed;
+mod git;
+#[cfg(feature = "github-app")]
+mod github_app;
+#[cfg(feature = "blocking")]
+mod github_batch;
+#[cfg(feature = "blocking")]
+mod gitlab;
+#[cfg(feature = "egui")]
+mod gui;
+mod history;
+mod homebrew;
+#[cfg(feature = "blocking")]
+mod http;
+mod install_method;
+mod installed;
 mod logic;
+#[cfg(feature = "markdown")]
+mod markdown;
+mod messages;
+#[cfg(feature = "blocking")]
+mod netrc;
+mod notes;
+mod oci;
+mod packagist;
+mod policy;
+mod prompt;
+mod repology;
+mod report;
+mod sanitize;
+mod scan;
+mod scoop;
+mod source;
+#[cfg(feature = "table")]
+mod table;
+mod tauri;
+mod terraform;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "keyring")]
+mod token_store;
+#[cfg(feature = "ratatui")]
+mod tui;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "webhook")]
+mod webhook;
 
 #[cfg(test)]
 mod test;
@@ -17,12 +124,205 @@ pub enum Source {
     Github(User),
     /// Check for updates on Gitea for a specific user and Gitea URL.
     Gitea(User, String),
+    /// Check for updates using the releases of a GitLab project.
+    ///
+    /// The project is its full path, including nested groups (e.g.
+    /// `group/subgroup/project`), or its numeric project ID, as shown on the
+    /// project's overview page. A `{name}` placeholder in the project is
+    /// replaced by the package name. The newest stable release is reported;
+    /// private projects need a `PRIVATE-TOKEN` header, see
+    /// [`Source::with_header`].
+    Gitlab {
+        /// The URL of the GitLab instance, e.g. `https://gitlab.com`.
+        url: String,
+        /// The path or numeric ID of the project, e.g. `group/subgroup/{name}`.
+        project: String,
+    },
+    /// Check for updates using the generic package registry of a GitLab
+    /// project.
+    ///
+    /// For tools that publish binaries as generic packages instead of
+    /// creating releases. The package name is the name of the generic
+    /// package, and its highest version (ignoring a leading `v`) is
+    /// reported. The project is given like for [`Source::Gitlab`].
+    GitlabPackages {
+        /// The URL of the GitLab instance, e.g. `https://gitlab.com`.
+        url: String,
+        /// The path or numeric ID of the project, e.g. `group/subgroup/project`.
+        project: String,
+    },
+    /// Check for updates of a Homebrew formula or cask.
+    ///
+    /// The stable version is read from the `formulae.brew.sh` JSON API, and
+    /// the upgrade command is `brew upgrade <name>`, so users who installed
+    /// with Homebrew are told about the version they can actually install.
+    Homebrew {
+        /// Whether the package is a cask rather than a formula.
+        cask: bool,
+    },
+    /// Check for updates of a package in the Arch User Repository.
+    ///
+    /// The version is queried with the AUR RPC `info` request. Only the
+    /// `pkgver` is compared; the epoch and `pkgrel` are ignored, so the
+    /// packaged version can be compared with the upstream release.
+    Aur,
+    /// Check for updates of a package in a Debian or Ubuntu suite.
+    ///
+    /// The versions are queried with the `madison` service of the Debian QA
+    /// team. Only the upstream part of the version is compared; the epoch
+    /// and the Debian revision are ignored, so packagers can compare the
+    /// upstream release with the version in the distribution.
+    Debian {
+        /// The suite, e.g. `bookworm`, `trixie-backports` or `noble`.
+        suite: String,
+        /// Whether to query the Ubuntu archive instead of the Debian one.
+        ubuntu: bool,
+    },
+    /// Check for updates using the versions Repology knows across all
+    /// distribution packagings.
+    ///
+    /// The package name is the Repology project name. The newest stable
+    /// version is reported, and [`UpdateInfo::versions_behind`] lists the
+    /// versions packaged by distributions, so maintainers can spot
+    /// packagings that lag behind or run ahead of the official release.
+    Repology,
+    /// Check for updates using the app manifest of a Scoop bucket.
+    ///
+    /// The manifest is fetched if it is an HTTP(S) URL, e.g. the raw GitHub
+    /// URL of `bucket/{name}.json`, and read from disk otherwise, e.g. from a
+    /// local bucket checkout. A `{name}` placeholder is replaced by the
+    /// package name. The upgrade command is `scoop update <name>`.
+    Scoop {
+        /// The URL or path of the manifest.
+        manifest: String,
+    },
+    /// Check for updates of a provider or module in a Terraform registry.
+    ///
+    /// The package name is the provider (`namespace/type`, e.g.
+    /// `hashicorp/aws`) or module (`namespace/name/system`, e.g.
+    /// `terraform-aws-modules/vpc/aws`) address. The registry protocol is
+    /// shared by `https://registry.terraform.io` and
+    /// `https://registry.opentofu.org`.
+    Terraform {
+        /// The URL of the registry.
+        registry: String,
+    },
+    /// Check for updates using a Tauri updater manifest (`latest.json`).
+    ///
+    /// The manifest's `version` and `notes` are reported. A `{name}`
+    /// placeholder in the URL is replaced by the package name. Manifests can
+    /// be generated with [`TauriManifest::from_github_release`].
+    Tauri {
+        /// The URL of the manifest.
+        url: String,
+    },
+    /// Check for updates using the tags of an image in an OCI registry.
+    ///
+    /// The tags are listed with the OCI distribution `tags/list` API, which is
+    /// supported by Docker Hub (`https://registry-1.docker.io`), GHCR
+    /// (`https://ghcr.io`), Quay and Harbor. The package name is the image
+    /// repository, e.g. `user/image` or `library/nginx`. Anonymous pull
+    /// tokens are requested automatically when the registry asks for them.
+    /// The highest tag that parses as a version (ignoring a leading `v`) is
+    /// reported; variant tags such as `1.2.0-alpine` are skipped.
+    Oci {
+        /// The URL of the registry, e.g. `https://ghcr.io`.
+        registry: String,
+        /// The username and password or access token for private registries.
+        credentials: Option<(String, String)>,
+    },
+    /// Check for updates of a Composer package on Packagist.
+    ///
+    /// The package name is the `vendor/package` name, e.g. `phpstan/phpstan`.
+    /// The highest published version is reported, and the upgrade command is
+    /// `composer global update <name>`.
+    Packagist,
+    /// Check for updates using any endpoint returning the latest version as JSON.
+    ///
+    /// The fields are extracted using [JSON pointers](https://datatracker.ietf.org/doc/html/rfc6901),
+    /// e.g. `/latest/version`. A `{name}` placeholder in the URL is replaced
+    /// by the package name, and a leading `v` in the version is ignored.
+    JsonEndpoint {
+        /// The URL of the endpoint.
+        url: String,
+        /// The JSON pointer to the latest version.
+        version_pointer: String,
+        /// The JSON pointer to the URL for more information, or `None` to use the endpoint URL.
+        url_pointer: Option<String>,
+        /// The JSON pointer to the release notes, if the endpoint provides them.
+        notes_pointer: Option<String>,
+    },
+    /// Check for updates using the tags of any git repository.
+    ///
+    /// The tags are listed with the git smart-HTTP protocol, like
+    /// `git ls-remote --tags`, so this works for forges without API support
+    /// and for bare git servers. The highest tag that parses as a version
+    /// (ignoring a leading `v`) is reported. A `{name}` placeholder in the URL
+    /// is replaced by the package name.
+    Git {
+        /// The HTTP(S) URL of the repository, e.g. `https://git.example.com/user/repo.git`.
+        url: String,
+    },
+    /// Check for updates using an Atom or RSS release feed.
+    ///
+    /// Feeds such as GitHub's `https://github.com/{user}/{repo}/releases.atom`
+    /// need no API access and are not rate limited. The version is taken from
+    /// the entry's link (e.g. `.../releases/tag/v1.2.0`) or title. A `{name}`
+    /// placeholder in the URL is replaced by the package name.
+    #[cfg(feature = "feed")]
+    Feed {
+        /// The URL of the feed.
+        url: String,
+    },
+    /// Check for updates by scraping a download page with a regular expression.
+    ///
+    /// The highest version matched anywhere on the page is reported. If the
+    /// pattern has a capture group, the first group is used as the version,
+    /// otherwise the whole match. A `{name}` placeholder in the URL is
+    /// replaced by the package name.
+    #[cfg(feature = "scrape")]
+    Scrape {
+        /// The URL of the page listing the versions.
+        url: String,
+        /// The regular expression matching a version, e.g. `my-tool-(\d+\.\d+\.\d+)\.tar\.gz`.
+        pattern: String,
+    },
+    /// Read the latest release from a JSON or TOML file.
+    ///
+    /// The file describes the release with a `version` and optional `notes`
+    /// and `url` fields, e.g. `{"version": "1.2.0", "notes": "- Faster builds"}`.
+    /// Files ending in `.toml` are parsed as TOML, all others as JSON. A
+    /// `{name}` placeholder in the path is replaced by the package name, so
+    /// one directory, e.g. on a network share, can describe several packages.
+    File(PathBuf),
+    /// Try each source in order until one succeeds.
+    ///
+    /// Created by [`Source::chain`].
+    Chain(Vec<Self>),
+    /// Query all sources concurrently and report the highest version found.
+    ///
+    /// Created by [`Source::highest`].
+    Highest(Vec<Self>),
+    /// Send extra headers with every request to the source.
+    ///
+    /// Created by [`Source::with_header`].
+    Headers {
+        /// The source to check.
+        source: Box<Self>,
+        /// The `(name, value)` pairs of the headers.
+        headers: Vec<(String, String)>,
+    },
+    /// Check for updates using a user-provided [`UpdateSource`].
+    Custom(Box<dyn UpdateSource + Send + Sync>),
 }
 
 /// Prints update information for a package from the specified source.
 ///
 /// This is a convenience function that checks for updates and prints the result
-/// directly to stdout if an update is available.
+/// directly to stderr, or the stream chosen with [`set_output`], if an update
+/// is available. Nothing is checked if the
+/// user opted out by setting [`NO_UPDATE_CHECK`]; use [`Checker`] for
+/// tool-specific opt-out variables and CI detection.
 ///
 /// # Arguments
 ///
@@ -44,18 +344,250 @@ pub enum Source {
 /// // Check Gitea
 /// print_check("my-repo", "0.1.0", Source::Gitea("username".to_string(), "https://gitea.example.com".to_string()));
 /// ```
+#[cfg(feature = "blocking")]
 pub fn print_check(name: &str, current_version: &str, source: Source) {
-    let result = match source {
-        Source::CratesIo => check_crates_io(name, current_version),
-        Source::Github(user) => check_github(name, &user, current_version),
-        Source::Gitea(user, gitea_url) => {
-            let update_available = UpdateAvailable::new(name, current_version);
-            update_available.gitea(&user, &gitea_url)
-        }
-    };
-    if let Ok(info) = result {
-        info.print();
+    print_check_styled(name, current_version, source, DisplayStyle::Emoji);
+}
+
+/// Prints update information for a package in the given display style.
+///
+/// Works like [`print_check`], but renders the notification using `style`,
+/// for example as a boxed banner.
+///
+/// # Arguments
+///
+/// * `name` - The name of the package to check
+/// * `current_version` - The current version string (e.g., "1.0.0")
+/// * `source` - The source to check for updates
+/// * `style` - The display style of the notification
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::{print_check_styled, DisplayStyle, Source};
+///
+/// print_check_styled("serde", "1.0.0", Source::CratesIo, DisplayStyle::Boxed);
+/// ```
+#[cfg(feature = "blocking")]
+pub fn print_check_styled(name: &str, current_version: &str, source: Source, style: DisplayStyle) {
+    if checker::opted_out() || display::print_once_done() {
+        return;
+    }
+    if let Ok(info) = check_source(name, current_version, source) {
+        info.print_styled(style);
+    }
+}
+
+/// Prints update information for a package with the given verbosity.
+///
+/// Works like [`print_check`], but [`Verbosity::Minimal`] prints a single
+/// line like `serde 1.0.200 available (you have 1.0.0)` instead of the full
+/// notification, and [`Verbosity::Silent`] skips the check entirely.
+///
+/// # Arguments
+///
+/// * `name` - The name of the package to check
+/// * `current_version` - The current version string (e.g., "1.0.0")
+/// * `source` - The source to check for updates
+/// * `verbosity` - How much of the notification is printed
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::{print_check_verbosity, Source, Verbosity};
+///
+/// print_check_verbosity("serde", "1.0.0", Source::CratesIo, Verbosity::Minimal);
+/// ```
+#[cfg(feature = "blocking")]
+pub fn print_check_verbosity(
+    name: &str,
+    current_version: &str,
+    source: Source,
+    verbosity: Verbosity,
+) {
+    if verbosity == Verbosity::Silent || checker::opted_out() || display::print_once_done() {
+        return;
+    }
+    if let Ok(info) = check_source(name, current_version, source) {
+        info.print_verbosity(verbosity);
+    }
+}
+
+/// Prints update information for a package, reporting failures to a handler.
+///
+/// Works like [`print_check`], but instead of silently discarding errors,
+/// passes them to `on_error`. This keeps the fire-and-forget ergonomics while
+/// making it possible to find out why no notification was shown.
+///
+/// # Arguments
+///
+/// * `name` - The name of the package to check
+/// * `current_version` - The current version string (e.g., "1.0.0")
+/// * `source` - The source to check for updates
+/// * `on_error` - Called with the error if the check fails
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::{print_check_or_else, Source};
+///
+/// print_check_or_else("serde", "1.0.0", Source::CratesIo, |e| {
+///     eprintln!("Update check failed: {e}");
+/// });
+/// ```
+#[cfg(feature = "blocking")]
+pub fn print_check_or_else<F: FnOnce(&anyhow::Error)>(
+    name: &str,
+    current_version: &str,
+    source: Source,
+    on_error: F,
+) {
+    if checker::opted_out() || display::print_once_done() {
+        return;
+    }
+    match check_source(name, current_version, source) {
+        Ok(info) => info.print(),
+        Err(e) => on_error(&e),
+    }
+}
+
+/// Writes update information for a package from the specified source to a writer.
+///
+/// Works like [`print_check`], but sends the notification to `writer` instead
+/// of the [`output`] stream, so GUI apps and loggers can capture it.
+///
+/// # Arguments
+///
+/// * `name` - The name of the package to check
+/// * `current_version` - The current version string (e.g., "1.0.0")
+/// * `source` - The source to check for updates
+/// * `writer` - The writer to send the notification to
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::{print_check_to, Source};
+///
+/// print_check_to("serde", "1.0.0", Source::CratesIo, &mut std::io::stderr());
+///
+/// let mut buffer = Vec::new();
+/// print_check_to("serde", "1.0.0", Source::CratesIo, &mut buffer);
+/// ```
+#[cfg(feature = "blocking")]
+pub fn print_check_to<W: std::io::Write>(
+    name: &str,
+    current_version: &str,
+    source: Source,
+    writer: &mut W,
+) {
+    if checker::opted_out() || display::print_once_done() {
+        return;
     }
+    if let Ok(info) = check_source(name, current_version, source)
+        && info.is_update_available
+        && display::claim_print()
+    {
+        let _ = info.write_to(writer);
+    }
+}
+
+/// Checks for updates of a package on the given source.
+#[expect(
+    clippy::needless_pass_by_value,
+    reason = "The print functions take ownership of the source"
+)]
+#[cfg(feature = "blocking")]
+fn check_source(name: &str, current_version: &str, source: Source) -> anyhow::Result<UpdateInfo> {
+    source.check(name, current_version)
+}
+
+/// Checks the calling crate for updates.
+///
+/// Expands `env!("CARGO_PKG_NAME")` and `env!("CARGO_PKG_VERSION")` at the
+/// call site, so applications do not have to repeat their own name and
+/// version. Without arguments, crates.io is checked.
+///
+/// # Returns
+///
+/// Returns a `Result<UpdateInfo, anyhow::Error>`, like [`UpdateSource::check`].
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::{Source, check_self};
+///
+/// if let Ok(info) = check_self!() {
+///     info.print();
+/// }
+///
+/// match check_self!(Source::Github("bircni".to_string())) {
+///     Ok(info) => info.print(),
+///     Err(e) => eprintln!("Error checking for updates: {e}"),
+/// }
+/// ```
+#[cfg(feature = "blocking")]
+#[macro_export]
+macro_rules! check_self {
+    () => {
+        $crate::check_self!($crate::Source::CratesIo)
+    };
+    ($source:expr $(,)?) => {
+        $crate::UpdateSource::check(&$source, env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+    };
+}
+
+/// Creates the source of the calling crate from its `repository` manifest field.
+///
+/// Reads `CARGO_PKG_REPOSITORY` at compile time and recognizes GitHub,
+/// GitLab and Gitea URLs, see [`Source::parse_with_repository`].
+///
+/// # Returns
+///
+/// Returns a `Result<(Source, String), anyhow::Error>` with the source and the
+/// name to check it with, like [`Source::from_repository`].
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::{UpdateSource, repository_source};
+///
+/// let (source, name) = repository_source!().unwrap();
+/// if let Ok(info) = source.check(&name, env!("CARGO_PKG_VERSION")) {
+///     info.print();
+/// }
+/// ```
+#[macro_export]
+macro_rules! repository_source {
+    () => {
+        $crate::Source::from_repository(env!("CARGO_PKG_REPOSITORY"), env!("CARGO_PKG_NAME"))
+    };
+}
+
+/// Checks the calling crate for updates on the forge of its repository.
+///
+/// Combines [`repository_source!`] and [`check_self!`] for zero-config
+/// update checks of crates with a `repository` in their manifest.
+///
+/// # Returns
+///
+/// Returns a `Result<UpdateInfo, anyhow::Error>`.
+///
+/// # Examples
+///
+/// ```rust
+/// match update_available::check_repository!() {
+///     Ok(info) => info.print(),
+///     Err(e) => eprintln!("Error checking for updates: {e}"),
+/// }
+/// ```
+#[cfg(feature = "blocking")]
+#[macro_export]
+macro_rules! check_repository {
+    () => {
+        $crate::repository_source!().and_then(|(source, name)| {
+            $crate::UpdateSource::check(&source, &name, env!("CARGO_PKG_VERSION"))
+        })
+    };
 }
 
 /// Checks for updates on crates.io for the specified package.
@@ -91,6 +623,7 @@ pub fn print_check(name: &str, current_version: &str, source: Source) {
 ///     Err(e) => eprintln!("Error checking for updates: {}", e),
 /// }
 /// ```
+#[cfg(feature = "blocking")]
 pub fn check_crates_io(name: &str, current_version: &str) -> anyhow::Result<UpdateInfo> {
     let update_available = UpdateAvailable::new(name, current_version);
     update_available.crates_io()
@@ -100,6 +633,8 @@ pub fn check_crates_io(name: &str, current_version: &str) -> anyhow::Result<Upda
 ///
 /// This function queries the GitHub API to check if a newer version
 /// of the specified repository is available.
+/// If several releases were skipped, their notes are aggregated into the
+/// changelog, newest first.
 ///
 /// # Arguments
 ///
@@ -131,6 +666,7 @@ pub fn check_crates_io(name: &str, current_version: &str) -> anyhow::Result<Upda
 ///     Err(e) => eprintln!("Error checking for updates: {}", e),
 /// }
 /// ```
+#[cfg(feature = "blocking")]
 pub fn check_github(name: &str, user: &str, current_version: &str) -> anyhow::Result<UpdateInfo> {
     let update_available = UpdateAvailable::new(name, current_version);
     update_available.github(user)
@@ -140,6 +676,8 @@ pub fn check_github(name: &str, user: &str, current_version: &str) -> anyhow::Re
 ///
 /// This function queries the Gitea API to check if a newer version
 /// of the specified repository is available.
+/// If several releases were skipped, their notes are aggregated into the
+/// changelog, newest first.
 ///
 /// # Arguments
 ///
@@ -161,6 +699,7 @@ pub fn check_github(name: &str, user: &str, current_version: &str) -> anyhow::Re
 /// * The version strings cannot be parsed
 /// * The response format is unexpected
 /// * The repository does not exist or has no releases
+/// * The Gitea URL is invalid or uses plain HTTP without permission
 ///
 /// # Examples
 ///
@@ -172,6 +711,7 @@ pub fn check_github(name: &str, user: &str, current_version: &str) -> anyhow::Re
 ///     Err(e) => eprintln!("Error checking for updates: {}", e),
 /// }
 /// ```
+#[cfg(feature = "blocking")]
 pub fn check_gitea(
     name: &str,
     user: &str,
@@ -181,3 +721,349 @@ pub fn check_gitea(
     let update_available = UpdateAvailable::new(name, current_version);
     update_available.gitea(user, gitea_url)
 }
+
+/// Checks how far a nightly build is behind the default branch on GitHub.
+///
+/// For tools built from git, whose version embeds the commit they were built
+/// from (e.g. `1.2.3-abc1234` or `1.2.3-5-gabc1234`), compares that commit
+/// with the head of the repository's default branch instead of comparing
+/// releases.
+///
+/// # Arguments
+///
+/// * `name` - The name of the repository to check
+/// * `user` - The GitHub username or organization that owns the repository
+/// * `current_version` - The current version, ending in a commit hash
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The current version does not contain a commit hash
+/// * The network request fails
+/// * The GitHub API returns an error, e.g. because the commit is unknown
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::check_github_commits;
+///
+/// match check_github_commits("my-repo", "username", "1.2.3-abc1234") {
+///     Ok(info) => println!("{info}"),
+///     Err(e) => eprintln!("Error checking for updates: {e}"),
+/// }
+/// ```
+#[cfg(feature = "blocking")]
+pub fn check_github_commits(
+    name: &str,
+    user: &str,
+    current_version: &str,
+) -> anyhow::Result<CommitInfo> {
+    let api_url = format!("{}/repos/{user}/{name}", crate::config::github_api_url());
+    logic::commits(name, &api_url, "GitHub", current_version)
+}
+
+/// Checks how far a nightly build is behind the default branch on Gitea.
+///
+/// Works like [`check_github_commits`] for a Gitea instance.
+///
+/// # Arguments
+///
+/// * `name` - The name of the repository to check
+/// * `user` - The Gitea username or organization that owns the repository
+/// * `gitea_url` - The base URL of the Gitea instance (e.g., <https://gitea.example.com>)
+/// * `current_version` - The current version, ending in a commit hash
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The current version does not contain a commit hash
+/// * The Gitea URL is invalid or uses plain HTTP without permission
+/// * The network request fails
+/// * The Gitea API returns an error, e.g. because the commit is unknown
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::check_gitea_commits;
+///
+/// match check_gitea_commits("my-repo", "username", "https://gitea.example.com", "1.2.3-abc1234") {
+///     Ok(info) => println!("{info}"),
+///     Err(e) => eprintln!("Error checking for updates: {e}"),
+/// }
+/// ```
+#[cfg(feature = "blocking")]
+pub fn check_gitea_commits(
+    name: &str,
+    user: &str,
+    gitea_url: &str,
+    current_version: &str,
+) -> anyhow::Result<CommitInfo> {
+    let gitea_url = crate::config::forge_url(gitea_url)?;
+    let api_url = format!("{gitea_url}/api/v1/repos/{user}/{name}");
+    logic::commits(name, &api_url, "Gitea", current_version)
+}
+
+/// Scans a cargo workspace and checks its crates.io dependencies for updates.
+///
+/// The manifest may describe a single package, a virtual workspace, or a
+/// workspace with a root package. Members listed in `[workspace.members]`
+/// (including `dir/*` globs) are scanned, and dependencies inherited via
+/// `workspace = true` are resolved from `[workspace.dependencies]`. The
+/// current version of each dependency is taken from the shared `Cargo.lock`;
+/// path, git and alternate registry dependencies are skipped.
+///
+/// # Arguments
+///
+/// * `manifest_path` - The path to the workspace root `Cargo.toml`
+///
+/// # Returns
+///
+/// Returns a `Result<WorkspaceReport, anyhow::Error>` containing the results
+/// for each member, or an error if the workspace cannot be read.
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * A manifest or the lockfile cannot be read
+/// * A manifest or the lockfile cannot be parsed
+///
+/// Errors from individual dependency checks are reported per dependency in
+/// the returned [`WorkspaceReport`].
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::scan_workspace;
+///
+/// match scan_workspace("Cargo.toml") {
+///     Ok(report) => {
+///         for member in report.members {
+///             for dependency in member.dependencies {
+///                 if let Ok(info) = dependency.result {
+///                     if info.is_update_available {
+///                         println!("{}: {} -> {}", member.name, dependency.name, info.latest_version);
+///                     }
+///                 }
+///             }
+///         }
+///     }
+///     Err(e) => eprintln!("Error scanning workspace: {}", e),
+/// }
+/// ```
+#[cfg(feature = "blocking")]
+pub fn scan_workspace(
+    manifest_path: impl AsRef<std::path::Path>,
+) -> anyhow::Result<WorkspaceReport> {
+    scan::scan(manifest_path.as_ref())
+}
+
+/// Checks a package on several sources concurrently.
+///
+/// This is useful when releases are published to several places at slightly
+/// different times, e.g. crates.io and GitHub. Use [`SourcesReport::highest`]
+/// for the highest version found, or [`Source::highest`] to get it directly.
+///
+/// # Arguments
+///
+/// * `name` - The name of the package to check
+/// * `current_version` - The current version string (e.g., "1.0.0")
+/// * `sources` - The sources to query
+///
+/// # Returns
+///
+/// Returns a [`SourcesReport`] with the result of each source, in the order
+/// the sources were given.
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::{check_sources, Source};
+///
+/// let sources = [Source::CratesIo, Source::Github("serde-rs".to_string())];
+/// let report = check_sources("serde", "1.0.0", &sources);
+/// if let Some(info) = report.highest() {
+///     println!("{info}");
+/// }
+/// for result in &report.results {
+///     if let Err(e) = result {
+///         eprintln!("A source failed: {e}");
+///     }
+/// }
+/// ```
+#[cfg(feature = "blocking")]
+#[must_use]
+pub fn check_sources(name: &str, current_version: &str, sources: &[Source]) -> SourcesReport {
+    SourcesReport {
+        results: source::concurrently(sources, |source| source.check(name, current_version)),
+    }
+}
+
+/// Checks the latest releases of many GitHub repositories at once.
+///
+/// Instead of one REST request per repository, the repositories are queried
+/// with a single GraphQL request per 50 repositories, which drastically
+/// reduces the rate-limit consumption when checking plugin ecosystems.
+/// GitHub's GraphQL API requires a token; a fine-grained token without any
+/// permissions is enough for public repositories.
+///
+/// Unlike [`check_github`], the changelog only contains the notes of the
+/// latest release and [`UpdateInfo::versions_behind`] is empty.
+///
+/// # Arguments
+///
+/// * `repositories` - The `("user/repo", current_version)` pairs to check
+/// * `token` - A GitHub access token
+///
+/// # Returns
+///
+/// Returns an [`UpdateReport`] with the result of every repository, named
+/// `user/repo`, in the order the repositories were given.
+///
+/// # Errors
+///
+/// Returns an error if a GraphQL request fails. Errors of single
+/// repositories, e.g. a repository without releases, are returned in the
+/// report.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use update_available::check_github_batch;
+///
+/// let token = std::env::var("GITHUB_TOKEN").unwrap();
+/// let plugins = [("user/plugin-a", "1.0.0"), ("user/plugin-b", "0.3.1")];
+/// let report = check_github_batch(&plugins, &token).unwrap();
+/// for plugin in report.outdated() {
+///     println!("{} can be updated", plugin.name);
+/// }
+/// ```
+#[cfg(feature = "blocking")]
+pub fn check_github_batch(
+    repositories: &[(&str, &str)],
+    token: &str,
+) -> anyhow::Result<UpdateReport> {
+    github_batch::check(repositories, token)
+}
+
+/// Checks many packages on a source concurrently.
+///
+/// At most [`BatchLimits::DEFAULT_MAX_PARALLEL`] packages are checked at the
+/// same time; use [`check_many_with_limits`] to change the limits.
+///
+/// # Arguments
+///
+/// * `packages` - The `(name, current_version)` pairs to check
+/// * `source` - The source to check the packages on
+///
+/// # Returns
+///
+/// Returns an [`UpdateReport`] with the result of every package, in the
+/// order the packages were given.
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::{Source, check_many};
+///
+/// let report = check_many(&[("serde", "1.0.0"), ("anyhow", "1.0.0")], &Source::CratesIo);
+/// for package in report.outdated() {
+///     println!("{} can be updated", package.name);
+/// }
+/// ```
+#[cfg(feature = "blocking")]
+#[must_use]
+pub fn check_many(packages: &[(&str, &str)], source: &Source) -> UpdateReport {
+    check_many_with_limits(packages, source, BatchLimits::default())
+}
+
+/// Checks many packages on a source concurrently, within `limits`.
+///
+/// Keeps large batches, e.g. from a dependency scan, from sending hundreds of
+/// requests at once, which services like crates.io answer by throttling.
+///
+/// # Arguments
+///
+/// * `packages` - The `(name, current_version)` pairs to check
+/// * `source` - The source to check the packages on
+/// * `limits` - How many packages and requests per host run at the same time
+///
+/// # Returns
+///
+/// Returns an [`UpdateReport`] with the result of every package, in the
+/// order the packages were given.
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::{BatchLimits, Source, check_many_with_limits};
+///
+/// let report = check_many_with_limits(
+///     &[("serde", "1.0.0"), ("anyhow", "1.0.0")],
+///     &Source::CratesIo,
+///     BatchLimits::new(2),
+/// );
+/// print!("{report}");
+/// ```
+#[cfg(feature = "blocking")]
+#[must_use]
+pub fn check_many_with_limits(
+    packages: &[(&str, &str)],
+    source: &Source,
+    limits: BatchLimits,
+) -> UpdateReport {
+    let packages: Vec<(String, String)> = packages
+        .iter()
+        .map(|(name, current_version)| ((*name).to_owned(), (*current_version).to_owned()))
+        .collect();
+    let results = logic::check_many(&packages, source, limits);
+    packages
+        .into_iter()
+        .zip(results)
+        .map(|((name, current_version), result)| PackageReport {
+            name,
+            current_version,
+            result,
+        })
+        .collect()
+}
+
+/// Checks all binaries installed with `cargo install` for updates.
+///
+/// This function reads `.crates2.json` from the cargo home directory
+/// (`$CARGO_HOME`, or `~/.cargo` if unset) and checks every package that was
+/// installed from crates.io. Packages installed from git or a local path are
+/// skipped.
+///
+/// # Returns
+///
+/// Returns a `Result<InstalledReport, anyhow::Error>` containing the results
+/// for each installed package, or an error if the install list cannot be read.
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The cargo home directory cannot be determined
+/// * `.crates2.json` cannot be read
+/// * `.crates2.json` cannot be parsed
+///
+/// Errors from individual package checks are reported per package in the
+/// returned [`InstalledReport`].
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::check_installed;
+///
+/// match check_installed() {
+///     Ok(report) => {
+///         for installed in report.outdated() {
+///             println!("{} ({}) is outdated", installed.name, installed.bins.join(", "));
+///         }
+///     }
+///     Err(e) => eprintln!("Error checking installed binaries: {}", e),
+/// }
+/// ```
+#[cfg(feature = "blocking")]
+pub fn check_installed() -> anyhow::Result<InstalledReport> {
+    installed::check(&installed::default_path()?)
+}
@@ -1,7 +1,14 @@
-use crate::{
-    UpdateAvailable,
-    data::{CratesResponse, GiteaHubResponse, UpdateInfo},
-};
+#![cfg_attr(
+    feature = "blocking",
+    expect(clippy::redundant_pub_crate, reason = "This is a library module")
+)]
+use crate::UpdateAvailable;
+#[cfg(feature = "blocking")]
+use crate::data::{CratesResponse, GiteaHubResponse, GiteaTag, ReleaseInfo, UpdateInfo};
+#[cfg(feature = "blocking")]
+use crate::oci::{BearerChallenge, TokenResponse};
+#[cfg(feature = "blocking")]
+use crate::{Source, UpdateSource as _};
 
 impl UpdateAvailable {
     /// Creates a new `UpdateAvailable` instance.
@@ -37,25 +44,65 @@ impl UpdateAvailable {
     /// * The response format is unexpected
     #[cfg(feature = "blocking")]
     pub(crate) fn crates_io(&self) -> anyhow::Result<UpdateInfo> {
-        let url = format!("https://crates.io/api/v1/crates/{}", self.name);
-        let mut response = ureq::get(&url)
-            .header("User-Agent", "update-available-lib")
-            .call()?;
+        UpdateInfo::from_crates(self.fetch_crate()?, &self.current_version)
+    }
+
+    /// Fetches the latest release of the crate from crates.io.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response is unexpected.
+    #[cfg(feature = "blocking")]
+    pub(crate) fn crates_io_latest(&self) -> anyhow::Result<ReleaseInfo> {
+        Ok(ReleaseInfo::from_crates(self.fetch_crate()?))
+    }
+
+    /// Fetches the crate's information from the crates.io API, or from the
+    /// sparse index if one is configured.
+    #[cfg(feature = "blocking")]
+    fn fetch_crate(&self) -> anyhow::Result<CratesResponse> {
+        if let Some(index) = crate::config::sparse_index_url() {
+            return self.fetch_index(&index);
+        }
+        let mut response = crate::http::get(&self.crates_io_url()).call()?;
 
         if response.status().is_success() {
-            let json: CratesResponse = response.body_mut().read_json()?;
-            let info = UpdateInfo::from_crates(json, &self.current_version)?;
-            Ok(info)
+            Ok(response.body_mut().read_json()?)
         } else {
-            println!("Failed to fetch data from crates.io: {}", response.status());
             anyhow::bail!("Failed to fetch data from crates.io: {}", response.status());
         }
     }
 
+    /// Fetches the crate's versions from the sparse index at `index`.
+    #[cfg(feature = "blocking")]
+    fn fetch_index(&self, index: &str) -> anyhow::Result<CratesResponse> {
+        let url = format!("{index}/{}", sparse_index_path(&self.name));
+        let mut response = crate::http::get(&url).call()?;
+        if !response.status().is_success() {
+            anyhow::bail!(
+                "Failed to fetch data from sparse index: {}",
+                response.status()
+            );
+        }
+        CratesResponse::from_index(&response.body_mut().read_to_string()?)
+    }
+
+    /// Returns the crates.io API URL of the crate.
+    #[cfg(any(feature = "blocking", feature = "wasm"))]
+    pub(crate) fn crates_io_url(&self) -> String {
+        format!(
+            "{}/api/v1/crates/{}",
+            crate::config::crates_io_url(),
+            self.name
+        )
+    }
+
     /// Checks for updates on GitHub for the specified repository.
     ///
     /// This method queries the GitHub API to check if a newer version
     /// of the specified repository is available by looking at the latest release.
+    /// If several releases were skipped, their notes are aggregated into the
+    /// changelog, newest first.
     ///
     /// # Arguments
     ///
@@ -76,28 +123,67 @@ impl UpdateAvailable {
     /// * The repository does not exist or has no releases
     #[cfg(feature = "blocking")]
     pub(crate) fn github(&self, user: &str) -> anyhow::Result<UpdateInfo> {
-        let url = format!(
-            "https://api.github.com/repos/{user}/{}/releases/latest",
-            self.name
-        );
-        let mut response = ureq::get(url)
-            .header("User-Agent", "update-available-lib")
-            .call()?;
-
-        if response.status().is_success() {
-            let json: GiteaHubResponse = response.body_mut().read_json()?;
-            let info = UpdateInfo::from_gitea_or_hub(json, &self.current_version)?;
-            Ok(info)
-        } else {
-            println!("Failed to fetch data from GitHub: {}", response.status());
-            anyhow::bail!("Failed to fetch data from GitHub: {}", response.status());
+        let (latest, releases) = self.github_urls(user);
+        let json = fetch_latest_release(&latest, "GitHub")?;
+        let changelog_file = changelog_file_url(&json, GITHUB_RAW_PATH);
+        let mut info = UpdateInfo::from_gitea_or_hub(&self.name, json, &self.current_version)?;
+        if info.is_update_available {
+            info.changelog_url = changelog_file.filter(|url| exists(url));
+            if let Some(releases) = fetch_releases(&releases) {
+                info.apply_releases(&releases);
+            }
         }
+        info.upgrade_command = Some(self.github_upgrade_command(user));
+        Ok(info)
+    }
+
+    /// Fetches the latest release of the repository from GitHub.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response is unexpected.
+    #[cfg(feature = "blocking")]
+    pub(crate) fn github_latest(&self, user: &str) -> anyhow::Result<ReleaseInfo> {
+        let (latest, releases) = self.github_urls(user);
+        let json = fetch_latest_release(&latest, "GitHub")?;
+        let changelog_url = discover_changelog_url(&json, GITHUB_RAW_PATH);
+        let mut release =
+            ReleaseInfo::from_gitea_or_hub(json, &fetch_releases(&releases).unwrap_or_default())?;
+        release.upgrade_command = Some(self.github_upgrade_command(user));
+        release.changelog_url = changelog_url;
+        Ok(release)
+    }
+
+    /// Returns the GitHub API URLs of the latest release and the releases list.
+    #[cfg(any(feature = "blocking", feature = "wasm"))]
+    pub(crate) fn github_urls(&self, user: &str) -> (String, String) {
+        (
+            format!(
+                "{}/repos/{user}/{}/releases/latest",
+                crate::config::github_api_url(),
+                self.name
+            ),
+            format!(
+                "{}/repos/{user}/{}/releases?per_page=100",
+                crate::config::github_api_url(),
+                self.name
+            ),
+        )
+    }
+
+    /// Returns the GitHub CLI command that downloads the assets of the latest release.
+    #[cfg(any(feature = "blocking", feature = "wasm"))]
+    pub(crate) fn github_upgrade_command(&self, user: &str) -> String {
+        format!("gh release download --repo {user}/{}", self.name)
     }
 
     /// Checks for updates on Gitea for the specified repository.
     ///
     /// This method queries the Gitea API to check if a newer version
     /// of the specified repository is available by looking at the latest release.
+    /// If several releases were skipped, their notes are aggregated into the
+    /// changelog, newest first. Repositories without releases are checked by
+    /// their highest version tag.
     ///
     /// # Arguments
     ///
@@ -116,25 +202,768 @@ impl UpdateAvailable {
     /// * The Gitea API returns an error
     /// * The version strings cannot be parsed
     /// * The response format is unexpected
-    /// * The repository does not exist or has no releases
-    /// * The Gitea URL is invalid
+    /// * The repository does not exist or has neither releases nor version tags
+    /// * The Gitea URL is invalid or uses plain HTTP without permission
     #[cfg(feature = "blocking")]
     pub(crate) fn gitea(&self, user: &str, gitea_url: &str) -> anyhow::Result<UpdateInfo> {
-        let url = format!(
-            "{gitea_url}/api/v1/repos/{user}/{}/releases/latest",
-            self.name
+        let gitea_url = &crate::config::forge_url(gitea_url)?;
+        let (latest, releases) = self.gitea_urls(user, gitea_url);
+        let json = match fetch_latest_release(&latest, "Gitea") {
+            Err(e) if is_not_found(&e) => {
+                let release = self.gitea_tags(user, gitea_url)?;
+                return UpdateInfo::from_release(&self.name, release, &self.current_version);
+            }
+            result => result?,
+        };
+        let changelog_file = changelog_file_url(&json, GITEA_RAW_PATH);
+        let mut info = UpdateInfo::from_gitea_or_hub(&self.name, json, &self.current_version)?;
+        if info.is_update_available {
+            info.changelog_url = changelog_file.filter(|url| exists(url));
+            if let Some(releases) = fetch_releases(&releases) {
+                info.apply_releases(&releases);
+            }
+        }
+        Ok(info)
+    }
+
+    /// Fetches the latest release of the repository from Gitea.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response is unexpected.
+    #[cfg(feature = "blocking")]
+    pub(crate) fn gitea_latest(&self, user: &str, gitea_url: &str) -> anyhow::Result<ReleaseInfo> {
+        let gitea_url = &crate::config::forge_url(gitea_url)?;
+        let (latest, releases) = self.gitea_urls(user, gitea_url);
+        let json = match fetch_latest_release(&latest, "Gitea") {
+            Err(e) if is_not_found(&e) => return self.gitea_tags(user, gitea_url),
+            result => result?,
+        };
+        let changelog_url = discover_changelog_url(&json, GITEA_RAW_PATH);
+        let mut release =
+            ReleaseInfo::from_gitea_or_hub(json, &fetch_releases(&releases).unwrap_or_default())?;
+        release.changelog_url = changelog_url;
+        Ok(release)
+    }
+
+    /// Reads the latest version from the tags of a Gitea repository, for
+    /// repositories that are tagged but have no releases.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tags cannot be fetched or none is a version.
+    #[cfg(feature = "blocking")]
+    fn gitea_tags(&self, user: &str, gitea_url: &str) -> anyhow::Result<ReleaseInfo> {
+        let tags: Vec<GiteaTag> = fetch_pages(
+            &format!(
+                "{gitea_url}/api/v1/repos/{user}/{}/tags?limit=50",
+                self.name
+            ),
+            "Gitea",
+        )?;
+        ReleaseInfo::from_gitea_tags(&tags, &format!("{gitea_url}/{user}/{}", self.name))
+    }
+
+    /// Returns the Gitea API URLs of the latest release and the releases list.
+    #[cfg(any(feature = "blocking", feature = "wasm"))]
+    pub(crate) fn gitea_urls(&self, user: &str, gitea_url: &str) -> (String, String) {
+        (
+            format!(
+                "{gitea_url}/api/v1/repos/{user}/{}/releases/latest",
+                self.name
+            ),
+            format!(
+                "{gitea_url}/api/v1/repos/{user}/{}/releases?limit=50",
+                self.name
+            ),
+        )
+    }
+}
+
+impl UpdateAvailable {
+    /// Checks a GitHub or Gitea repository, reporting the stable release with
+    /// the highest version instead of the one published last.
+    ///
+    /// All pages of the releases list are fetched, up to
+    /// [`MAX_RELEASE_PAGES`]. If `pattern` is set, only releases with an
+    /// asset matching it are considered, so a release without a build for the
+    /// user's platform is not announced until one is uploaded.
+    ///
+    /// # Arguments
+    ///
+    /// * `releases_url` - The API URL of the releases list
+    /// * `forge` - The name of the service, used in error messages
+    /// * `raw_path` - The path of raw files below the repository, used to
+    ///   find the changelog of a release without notes
+    /// * `pattern` - The glob pattern an asset name must match, if any
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the releases cannot be fetched, no release
+    /// qualifies or the versions cannot be parsed.
+    #[cfg(feature = "blocking")]
+    pub(crate) fn highest_release(
+        &self,
+        releases_url: &str,
+        forge: &str,
+        raw_path: &str,
+        pattern: Option<&str>,
+    ) -> anyhow::Result<UpdateInfo> {
+        let releases = fetch_pages(releases_url, forge)?;
+        UpdateInfo::from_highest_release(
+            &self.name,
+            releases,
+            &self.current_version,
+            pattern,
+            |release| discover_changelog_url(release, raw_path),
+        )
+    }
+}
+
+/// The maximum number of pages of a releases or tags list fetched when
+/// looking for the highest version.
+#[cfg(feature = "blocking")]
+const MAX_RELEASE_PAGES: usize = 10;
+
+/// Fetches the pages of a GitHub, Gitea or GitLab list, such as releases or
+/// tags.
+///
+/// Stops at the first page shorter than the first one, which is the page
+/// size the server allows, or after [`MAX_RELEASE_PAGES`] pages.
+#[cfg(feature = "blocking")]
+fn fetch_pages<T: serde::de::DeserializeOwned>(url: &str, forge: &str) -> anyhow::Result<Vec<T>> {
+    let mut items = Vec::new();
+    let mut page_size = None;
+    for page in 1..=MAX_RELEASE_PAGES {
+        let separator = if url.contains('?') { '&' } else { '?' };
+        let mut response = crate::http::get(&format!("{url}{separator}page={page}")).call()?;
+        if !response.status().is_success() {
+            anyhow::bail!("Failed to fetch data from {forge}: {}", response.status());
+        }
+        let batch: Vec<T> = response.body_mut().read_json()?;
+        let len = batch.len();
+        items.extend(batch);
+        if len == 0 || len < *page_size.get_or_insert(len) {
+            break;
+        }
+    }
+    Ok(items)
+}
+
+/// Returns whether a request failed because the resource does not exist.
+#[cfg(feature = "blocking")]
+fn is_not_found(error: &anyhow::Error) -> bool {
+    matches!(
+        error.downcast_ref::<ureq::Error>(),
+        Some(ureq::Error::StatusCode(404))
+    )
+}
+
+/// Fetches the latest release from a GitHub or Gitea API URL.
+///
+/// `forge` names the service in error messages.
+#[cfg(feature = "blocking")]
+fn fetch_latest_release(url: &str, forge: &str) -> anyhow::Result<GiteaHubResponse> {
+    let mut response = crate::http::get(url).call()?;
+
+    if response.status().is_success() {
+        Ok(response.body_mut().read_json()?)
+    } else {
+        anyhow::bail!("Failed to fetch data from {forge}: {}", response.status());
+    }
+}
+
+/// Returns the path of a crate's file in a sparse index, e.g. `se/rd/serde`.
+#[cfg(feature = "blocking")]
+pub(crate) fn sparse_index_path(name: &str) -> String {
+    let name = name.to_ascii_lowercase();
+    match name.len() {
+        0..=2 => format!("{}/{name}", name.len()),
+        3 => format!("3/{}/{name}", name.get(..1).unwrap_or_default()),
+        _ => format!(
+            "{}/{}/{name}",
+            name.get(..2).unwrap_or_default(),
+            name.get(2..4).unwrap_or_default()
+        ),
+    }
+}
+
+/// The path of raw files below a GitHub repository, followed by the tag.
+#[cfg(feature = "blocking")]
+pub(crate) const GITHUB_RAW_PATH: &str = "raw";
+
+/// The path of raw files below a Gitea repository, followed by the tag.
+#[cfg(feature = "blocking")]
+pub(crate) const GITEA_RAW_PATH: &str = "raw/tag";
+
+/// Returns the URL of `CHANGELOG.md` at the tag of a release without notes.
+///
+/// This is best-effort: the file is looked up with a `HEAD` request, and
+/// `None` is returned if the release has notes or the file does not exist.
+#[cfg(feature = "blocking")]
+fn discover_changelog_url(release: &GiteaHubResponse, raw_path: &str) -> Option<String> {
+    changelog_file_url(release, raw_path).filter(|url| exists(url))
+}
+
+/// Returns where `CHANGELOG.md` would be at the tag of a release without
+/// notes, without checking that it exists, or `None` if the release has
+/// notes.
+#[cfg(feature = "blocking")]
+fn changelog_file_url(release: &GiteaHubResponse, raw_path: &str) -> Option<String> {
+    if !release.has_empty_body() {
+        return None;
+    }
+    release.changelog_file_url(raw_path)
+}
+
+/// Returns whether a `HEAD` request for the URL succeeds.
+#[cfg(feature = "blocking")]
+fn exists(url: &str) -> bool {
+    crate::http::head(url)
+        .call()
+        .is_ok_and(|response| response.status().is_success())
+}
+
+/// Fetches the releases list of a GitHub or Gitea repository.
+///
+/// This is best-effort and returns `None` if the list cannot be fetched.
+#[cfg(feature = "blocking")]
+fn fetch_releases(url: &str) -> Option<Vec<GiteaHubResponse>> {
+    let mut response = crate::http::get(url).call().ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    response.body_mut().read_json().ok()
+}
+
+/// Compares the commit embedded in `current_version` with the head of the
+/// default branch of a GitHub or Gitea repository.
+///
+/// `api_url` is the API URL of the repository, e.g.
+/// `https://api.github.com/repos/user/repo`; `forge` names the service in
+/// error messages.
+///
+/// # Errors
+///
+/// Returns an error if the version contains no commit hash, a request fails
+/// or a response is unexpected.
+#[cfg(feature = "blocking")]
+pub(crate) fn commits(
+    name: &str,
+    api_url: &str,
+    forge: &str,
+    current_version: &str,
+) -> anyhow::Result<crate::CommitInfo> {
+    use crate::commits::{Branch, Comparison, Repository, commit_from_version};
+
+    let current_commit = commit_from_version(current_version).ok_or_else(|| {
+        anyhow::anyhow!("The version '{current_version}' does not contain a commit hash")
+    })?;
+    let repository: Repository = fetch_json(api_url, forge)?;
+    let branch = repository.default_branch;
+    let branch_head: Branch = fetch_json(&format!("{api_url}/branches/{branch}"), forge)?;
+    let latest_commit = branch_head.commit.sha;
+    let commits_behind = if latest_commit.starts_with(current_commit) {
+        0
+    } else {
+        let comparison: Comparison = fetch_json(
+            &format!("{api_url}/compare/{current_commit}...{latest_commit}"),
+            forge,
+        )?;
+        comparison.total_commits
+    };
+    Ok(crate::CommitInfo {
+        name: name.to_owned(),
+        current_commit: current_commit.to_owned(),
+        url: format!(
+            "{}/compare/{current_commit}...{branch}",
+            repository.html_url
+        ),
+        latest_commit,
+        branch,
+        commits_behind,
+    })
+}
+
+/// Fetches a JSON document from a GitHub or Gitea API URL.
+///
+/// `forge` names the service in error messages.
+#[cfg(feature = "blocking")]
+fn fetch_json<T: serde::de::DeserializeOwned>(url: &str, forge: &str) -> anyhow::Result<T> {
+    let mut response = crate::http::get(url).call()?;
+    if !response.status().is_success() {
+        anyhow::bail!("Failed to fetch data from {forge}: {}", response.status());
+    }
+    response
+        .body_mut()
+        .read_json()
+        .map_err(|e| anyhow::anyhow!("Failed to parse {forge} response: {e}"))
+}
+
+/// Fetches the latest release from a generic JSON endpoint.
+///
+/// # Errors
+///
+/// Returns an error if the request fails, the response is not JSON or the
+/// version cannot be extracted.
+#[cfg(feature = "blocking")]
+pub(crate) fn json_endpoint(
+    url: &str,
+    version_pointer: &str,
+    url_pointer: Option<&str>,
+    notes_pointer: Option<&str>,
+) -> anyhow::Result<ReleaseInfo> {
+    let mut response = crate::http::get(url).call()?;
+
+    if response.status().is_success() {
+        let json: serde_json::Value = response.body_mut().read_json()?;
+        ReleaseInfo::from_json(&json, url, version_pointer, url_pointer, notes_pointer)
+    } else {
+        anyhow::bail!("Failed to fetch data from {url}: {}", response.status());
+    }
+}
+
+/// Fetches the releases of a Composer package from Packagist.
+///
+/// # Errors
+///
+/// Returns an error if the name is not a `vendor/package` name, the request
+/// fails or no version is published.
+#[cfg(feature = "blocking")]
+pub(crate) fn packagist(name: &str) -> anyhow::Result<ReleaseInfo> {
+    let url = crate::packagist::metadata_url(name)?;
+    let mut response = crate::http::get(&url).call()?;
+
+    if response.status().is_success() {
+        let json = response.body_mut().read_to_string()?;
+        ReleaseInfo::from_packagist(&json, name)
+    } else {
+        anyhow::bail!("Failed to fetch data from Packagist: {}", response.status());
+    }
+}
+
+/// Fetches the stable version of a Homebrew formula or cask.
+///
+/// # Errors
+///
+/// Returns an error if the request fails or the package has no stable version.
+#[cfg(feature = "blocking")]
+pub(crate) fn homebrew(name: &str, cask: bool) -> anyhow::Result<ReleaseInfo> {
+    let kind = if cask { "cask" } else { "formula" };
+    let url = format!("https://formulae.brew.sh/api/{kind}/{name}.json");
+    let mut response = crate::http::get(&url).call()?;
+
+    if response.status().is_success() {
+        let json = response.body_mut().read_to_string()?;
+        ReleaseInfo::from_homebrew(&json, name, cask)
+    } else {
+        anyhow::bail!("Failed to fetch data from Homebrew: {}", response.status());
+    }
+}
+
+/// Fetches the version of a package from the AUR RPC.
+///
+/// # Errors
+///
+/// Returns an error if the request fails or the package does not exist.
+#[cfg(feature = "blocking")]
+pub(crate) fn aur(name: &str) -> anyhow::Result<ReleaseInfo> {
+    let mut response = crate::http::get("https://aur.archlinux.org/rpc/")
+        .query("v", "5")
+        .query("type", "info")
+        .query("arg[]", name)
+        .call()?;
+
+    if response.status().is_success() {
+        let json = response.body_mut().read_to_string()?;
+        ReleaseInfo::from_aur(&json, name)
+    } else {
+        anyhow::bail!("Failed to fetch data from the AUR: {}", response.status());
+    }
+}
+
+/// Fetches the packagings of a project from Repology.
+///
+/// # Errors
+///
+/// Returns an error if the request fails or the project is unknown.
+#[cfg(feature = "blocking")]
+pub(crate) fn repology(name: &str) -> anyhow::Result<ReleaseInfo> {
+    let mut response =
+        crate::http::get(&format!("https://repology.org/api/v1/project/{name}")).call()?;
+
+    if response.status().is_success() {
+        let json = response.body_mut().read_to_string()?;
+        ReleaseInfo::from_repology(&json, name)
+    } else {
+        anyhow::bail!("Failed to fetch data from Repology: {}", response.status());
+    }
+}
+
+/// Fetches the versions of a package in a Debian or Ubuntu suite from `madison`.
+///
+/// # Errors
+///
+/// Returns an error if the request fails or the package is not in the suite.
+#[cfg(feature = "blocking")]
+pub(crate) fn madison(name: &str, suite: &str, ubuntu: bool) -> anyhow::Result<ReleaseInfo> {
+    let mut request = crate::http::get("https://qa.debian.org/madison.php")
+        .query("package", name)
+        .query("s", suite)
+        .query("text", "on");
+    if ubuntu {
+        request = request.query("table", "ubuntu");
+    }
+    let mut response = request.call()?;
+
+    if response.status().is_success() {
+        let text = response.body_mut().read_to_string()?;
+        ReleaseInfo::from_madison(&text, name, suite, ubuntu)
+    } else {
+        anyhow::bail!("Failed to fetch data from madison: {}", response.status());
+    }
+}
+
+/// Fetches the versions of a provider or module from a Terraform registry.
+///
+/// # Errors
+///
+/// Returns an error if the address is invalid, the request fails or no
+/// version is published.
+#[cfg(feature = "blocking")]
+pub(crate) fn terraform_registry(registry: &str, name: &str) -> anyhow::Result<ReleaseInfo> {
+    let url = crate::terraform::versions_url(registry, name)?;
+    let mut response = crate::http::get(&url).call()?;
+
+    if response.status().is_success() {
+        let json = response.body_mut().read_to_string()?;
+        ReleaseInfo::from_terraform_registry(&json, registry, name)
+    } else {
+        anyhow::bail!(
+            "Failed to fetch data from {registry}: {}",
+            response.status()
         );
-        let mut response = ureq::get(url)
-            .header("User-Agent", "update-available-lib")
-            .call()?;
+    }
+}
 
-        if response.status().is_success() {
-            let json: GiteaHubResponse = response.body_mut().read_json()?;
-            let info = UpdateInfo::from_gitea_or_hub(json, &self.current_version)?;
-            Ok(info)
-        } else {
-            println!("Failed to fetch data from Gitea: {}", response.status());
-            anyhow::bail!("Failed to fetch data from Gitea: {}", response.status());
+/// Fetches a Tauri updater manifest.
+///
+/// # Errors
+///
+/// Returns an error if the request fails or the manifest cannot be parsed.
+#[cfg(feature = "blocking")]
+pub(crate) fn tauri(url: &str) -> anyhow::Result<ReleaseInfo> {
+    let mut response = crate::http::get(url).call()?;
+
+    if response.status().is_success() {
+        let json = response.body_mut().read_to_string()?;
+        ReleaseInfo::from_tauri_manifest(&json, url)
+    } else {
+        anyhow::bail!("Failed to fetch data from {url}: {}", response.status());
+    }
+}
+
+/// Fetches or reads a Scoop app manifest.
+///
+/// HTTP(S) URLs are fetched; anything else is read as a path.
+///
+/// # Errors
+///
+/// Returns an error if the manifest cannot be fetched, read or parsed.
+#[cfg(feature = "blocking")]
+pub(crate) fn scoop(manifest: &str, name: &str) -> anyhow::Result<ReleaseInfo> {
+    let json = if manifest.starts_with("https://") || manifest.starts_with("http://") {
+        let mut response = crate::http::get(manifest).call()?;
+        if !response.status().is_success() {
+            anyhow::bail!(
+                "Failed to fetch data from {manifest}: {}",
+                response.status()
+            );
+        }
+        response.body_mut().read_to_string()?
+    } else {
+        std::fs::read_to_string(manifest)
+            .map_err(|e| anyhow::anyhow!("Failed to read {manifest}: {e}"))?
+    };
+    ReleaseInfo::from_scoop_manifest(&json, manifest, name)
+}
+
+/// The maximum number of `tags/list` pages fetched from an OCI registry.
+#[cfg(feature = "blocking")]
+const OCI_MAX_PAGES: usize = 20;
+
+/// Lists the tags of an OCI image and picks the highest version.
+///
+/// If the registry answers with a bearer challenge, a pull token is requested
+/// from its token service, using `credentials` if given, and the request is
+/// repeated. Paginated tag lists are followed through their `Link` headers.
+///
+/// # Errors
+///
+/// Returns an error if a request fails, authentication is refused or no tag
+/// is a version.
+#[cfg(feature = "blocking")]
+pub(crate) fn oci(
+    registry: &str,
+    name: &str,
+    credentials: Option<&(String, String)>,
+) -> anyhow::Result<ReleaseInfo> {
+    let registry = registry.trim_end_matches('/');
+    let mut url = format!("{registry}/v2/{name}/tags/list");
+    let mut authorization = None;
+    let mut tags = Vec::new();
+    for _ in 0..OCI_MAX_PAGES {
+        let mut response = oci_get(&url, authorization.as_deref())?;
+        if response.status() == 401 && authorization.is_none() {
+            let challenge = response
+                .headers()
+                .get("www-authenticate")
+                .and_then(|header| header.to_str().ok())
+                .unwrap_or_default();
+            authorization = Some(oci_authorization(challenge, name, credentials)?);
+            response = oci_get(&url, authorization.as_deref())?;
+        }
+        if !response.status().is_success() {
+            anyhow::bail!(
+                "Failed to fetch tags from {registry}: {}",
+                response.status()
+            );
+        }
+        let next = response
+            .headers()
+            .get("link")
+            .and_then(|header| header.to_str().ok())
+            .and_then(|header| crate::oci::next_link(header, registry));
+        tags.extend(crate::oci::parse_tags(
+            &response.body_mut().read_to_string()?,
+        )?);
+        match next {
+            Some(next) => url = next,
+            None => break,
         }
     }
+    ReleaseInfo::from_oci_tags(&tags, registry, name)
+}
+
+/// Sends a GET request to an OCI registry without treating error statuses as
+/// errors, so authentication challenges can be answered.
+#[cfg(feature = "blocking")]
+fn oci_get(
+    url: &str,
+    authorization: Option<&str>,
+) -> anyhow::Result<ureq::http::Response<ureq::Body>> {
+    let mut request = crate::http::get(url)
+        .config()
+        .http_status_as_error(false)
+        .build()
+        .header("Accept", "application/json");
+    if let Some(authorization) = authorization {
+        request = request.header("Authorization", authorization);
+    }
+    Ok(request.call()?)
+}
+
+/// Answers an OCI registry's `WWW-Authenticate` challenge, returning the
+/// value of the `Authorization` header to retry with.
+///
+/// # Errors
+///
+/// Returns an error if the challenge is not supported or the token service
+/// refuses the request.
+#[cfg(feature = "blocking")]
+fn oci_authorization(
+    challenge: &str,
+    name: &str,
+    credentials: Option<&(String, String)>,
+) -> anyhow::Result<String> {
+    let basic = credentials
+        .map(|(username, password)| crate::http::basic_authorization(username, password));
+    if challenge
+        .trim_start()
+        .get(..6)
+        .is_some_and(|scheme| scheme.eq_ignore_ascii_case("basic "))
+    {
+        return basic.ok_or_else(|| anyhow::anyhow!("The registry requires credentials"));
+    }
+    let challenge = BearerChallenge::parse(challenge)
+        .ok_or_else(|| anyhow::anyhow!("Unsupported registry authentication: '{challenge}'"))?;
+    let mut request = crate::http::get(&challenge.token_url(name))
+        .config()
+        .http_status_as_error(false)
+        .build();
+    if let Some(basic) = &basic {
+        request = request.header("Authorization", basic);
+    }
+    let mut response = request.call()?;
+    if !response.status().is_success() {
+        anyhow::bail!(
+            "Failed to get a token from {}: {}",
+            challenge.realm,
+            response.status()
+        );
+    }
+    let token: TokenResponse = response.body_mut().read_json()?;
+    let token = token
+        .into_token()
+        .ok_or_else(|| anyhow::anyhow!("The token service returned no token"))?;
+    Ok(format!("Bearer {token}"))
+}
+
+/// Lists the tags of a git repository and picks the highest version.
+///
+/// # Errors
+///
+/// Returns an error if the request fails, the server does not speak the
+/// smart-HTTP protocol or no tag is a version.
+#[cfg(feature = "blocking")]
+pub(crate) fn git_tags(url: &str) -> anyhow::Result<ReleaseInfo> {
+    let refs_url = format!(
+        "{}/info/refs?service=git-upload-pack",
+        url.trim_end_matches('/')
+    );
+    let mut response = crate::http::get(&refs_url).call()?;
+
+    if response.status().is_success() {
+        let body = response.body_mut().read_to_vec()?;
+        ReleaseInfo::from_git_refs(&body, url)
+    } else {
+        anyhow::bail!("Failed to fetch data from {url}: {}", response.status());
+    }
+}
+
+/// Fetches the releases of a GitLab project and picks the newest stable one.
+///
+/// # Errors
+///
+/// Returns an error if the GitLab URL is invalid, the project is neither an
+/// ID nor a path, the request fails or no release is a version.
+#[cfg(feature = "blocking")]
+pub(crate) fn gitlab(gitlab_url: &str, project: &str) -> anyhow::Result<ReleaseInfo> {
+    let gitlab_url = &crate::config::forge_url(gitlab_url)?;
+    let releases: Vec<crate::gitlab::GitlabRelease> =
+        fetch_json(&crate::gitlab::releases_url(gitlab_url, project)?, "GitLab")?;
+    ReleaseInfo::from_gitlab_releases(&releases, &crate::gitlab::project_url(gitlab_url, project))
+}
+
+/// Lists the generic packages of a GitLab project and picks the highest
+/// version of the package named `name`.
+///
+/// # Errors
+///
+/// Returns an error if the GitLab URL is invalid, the project is neither an
+/// ID nor a path, the request fails or no package of that name has a version.
+#[cfg(feature = "blocking")]
+pub(crate) fn gitlab_packages(
+    gitlab_url: &str,
+    project: &str,
+    name: &str,
+) -> anyhow::Result<ReleaseInfo> {
+    let gitlab_url = &crate::config::forge_url(gitlab_url)?;
+    let packages: Vec<crate::gitlab::GitlabPackage> = fetch_pages(
+        &crate::gitlab::packages_url(gitlab_url, project, name)?,
+        "GitLab",
+    )?;
+    ReleaseInfo::from_gitlab_packages(
+        &packages,
+        name,
+        gitlab_url,
+        &crate::gitlab::project_url(gitlab_url, project),
+    )
+}
+
+/// Fetches an Atom or RSS feed and extracts the latest release.
+///
+/// # Errors
+///
+/// Returns an error if the request fails, the feed cannot be parsed or no
+/// entry has a version.
+#[cfg(all(feature = "blocking", feature = "feed"))]
+pub(crate) fn feed(url: &str) -> anyhow::Result<ReleaseInfo> {
+    let mut response = crate::http::get(url).call()?;
+
+    if response.status().is_success() {
+        let xml = response.body_mut().read_to_string()?;
+        ReleaseInfo::from_feed(&xml, url)
+    } else {
+        anyhow::bail!("Failed to fetch data from {url}: {}", response.status());
+    }
+}
+
+/// Fetches a page and extracts the latest version with a regular expression.
+///
+/// # Errors
+///
+/// Returns an error if the pattern is invalid, the request fails or no
+/// version is found on the page.
+#[cfg(all(feature = "blocking", feature = "scrape"))]
+pub(crate) fn scrape(url: &str, pattern: &str) -> anyhow::Result<ReleaseInfo> {
+    let pattern =
+        regex::Regex::new(pattern).map_err(|e| anyhow::anyhow!("Invalid version pattern: {e}"))?;
+    let mut response = crate::http::get(url).call()?;
+
+    if response.status().is_success() {
+        let page = response.body_mut().read_to_string()?;
+        ReleaseInfo::from_page(&page, url, &pattern)
+    } else {
+        anyhow::bail!("Failed to fetch data from {url}: {}", response.status());
+    }
+}
+
+/// Checks several packages on a source concurrently, within `limits`.
+///
+/// Each entry is a `(name, current_version)` pair. The packages are handed
+/// out to at most `limits.max_parallel` worker threads. The results are
+/// returned in the same order as the input.
+#[cfg(feature = "blocking")]
+pub(crate) fn check_many(
+    packages: &[(String, String)],
+    source: &Source,
+    limits: crate::BatchLimits,
+) -> Vec<anyhow::Result<UpdateInfo>> {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let mut config = crate::http::current();
+    if let Some(limit) = limits.per_host {
+        let mut limited = config.as_deref().cloned().unwrap_or_default();
+        limited.host_limiter = Some(Arc::new(crate::http::HostLimiter::new(limit)));
+        config = Some(Arc::new(limited));
+    }
+    let workers = limits.max_parallel.clamp(1, packages.len().max(1));
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<Option<anyhow::Result<UpdateInfo>>> =
+        packages.iter().map(|_| None).collect();
+    std::thread::scope(|scope| {
+        // All workers must be spawned before joining.
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    crate::http::with_config(config.clone(), || {
+                        let mut checked = Vec::new();
+                        loop {
+                            let index = next.fetch_add(1, Ordering::Relaxed);
+                            let Some((name, current_version)) = packages.get(index) else {
+                                break checked;
+                            };
+                            checked.push((index, source.check(name, current_version)));
+                        }
+                    })
+                })
+            })
+            .collect();
+        for handle in handles {
+            // A worker that panicked leaves its packages unchecked.
+            if let Ok(checked) = handle.join() {
+                for (index, result) in checked {
+                    if let Some(slot) = results.get_mut(index) {
+                        *slot = Some(result);
+                    }
+                }
+            }
+        }
+    });
+    results
+        .into_iter()
+        .map(|result| result.unwrap_or_else(|| Err(anyhow::anyhow!("Update check panicked"))))
+        .collect()
 }
@@ -0,0 +1,6 @@
+//! The `update-available` command-line tool.
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    update_available::cli::run()
+}
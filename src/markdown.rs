@@ -0,0 +1,35 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+
+/// Renders inline markdown (bold, italics, code spans, links) with ANSI styles.
+///
+/// Block-level structure is ignored, since changelog lines are rendered one
+/// at a time. Images are replaced by their alt text and raw HTML is dropped.
+#[expect(
+    clippy::wildcard_enum_match_arm,
+    reason = "Only inline markdown is styled, everything else is rendered as text"
+)]
+pub(crate) fn render_inline(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    for event in Parser::new(text) {
+        match event {
+            Event::Start(Tag::Strong | Tag::Heading { .. }) => output.push_str("\x1b[1m"),
+            Event::End(TagEnd::Strong | TagEnd::Heading(_)) => output.push_str("\x1b[22m"),
+            Event::Start(Tag::Emphasis) => output.push_str("\x1b[3m"),
+            Event::End(TagEnd::Emphasis) => output.push_str("\x1b[23m"),
+            Event::Start(Tag::Strikethrough) => output.push_str("\x1b[9m"),
+            Event::End(TagEnd::Strikethrough) => output.push_str("\x1b[29m"),
+            Event::Start(Tag::Link { .. }) => output.push_str("\x1b[4m"),
+            Event::End(TagEnd::Link) => output.push_str("\x1b[24m"),
+            Event::Code(code) => {
+                output.push_str("\x1b[36m");
+                output.push_str(&code);
+                output.push_str("\x1b[39m");
+            }
+            Event::Text(text) => output.push_str(&text),
+            Event::SoftBreak | Event::HardBreak => output.push(' '),
+            _ => {}
+        }
+    }
+    output
+}
@@ -0,0 +1,223 @@
+/// The texts of an update notification, in a specific language.
+///
+/// The built-in translations cover English, German, French and Japanese.
+/// Select one with [`crate::UpdateDisplay::messages`]; the emoji icons and
+/// box of the [`crate::DisplayStyle`] are added around the texts. The texts
+/// can also be replaced to re-word or re-brand the notification.
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::{check_crates_io, DisplayStyle, Messages};
+///
+/// if let Ok(info) = check_crates_io("serde", "1.0.0") {
+///     let messages = Messages::from_env().unwrap_or_default();
+///     println!("{}", info.styled(DisplayStyle::Emoji).messages(messages));
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Messages<'a> {
+    /// The headline, e.g. "A new version is available!".
+    pub header: &'a str,
+    /// The label before the versions, e.g. "Latest version:".
+    pub latest_label: &'a str,
+    /// The text after the number of skipped releases, e.g. "releases behind".
+    pub releases_behind: &'a str,
+    /// The label before the changelog, e.g. "Changelog:".
+    pub changelog_label: &'a str,
+    /// The last changelog line if lines were omitted, e.g. "(and more...)".
+    pub and_more: &'a str,
+    /// The prefix of the breaking-change notice in plain output, e.g. "Warning".
+    pub warning: &'a str,
+    /// The breaking-change notice, e.g. "This release contains breaking changes".
+    pub breaking_changes: &'a str,
+    /// The label before the upgrade command, e.g. "Update with:".
+    pub upgrade_label: &'a str,
+    /// The label before the URL, e.g. "More info:".
+    pub more_info_label: &'a str,
+    /// The text after the latest version in a one-line notification, e.g.
+    /// "available".
+    pub available: &'a str,
+    /// The text before the current version in a one-line notification, e.g.
+    /// "you have".
+    pub you_have: &'a str,
+    /// The reply to a requested check without an update, e.g. "You're
+    /// already using the latest version!".
+    pub up_to_date: &'a str,
+    /// The reply to a requested check while checks are turned off, e.g.
+    /// "Update checks are disabled".
+    pub disabled: &'a str,
+    /// The text before the variable turning checks off, e.g. "Update checks
+    /// are disabled by".
+    pub disabled_by: &'a str,
+    /// The reply to a requested check in CI, e.g. "Update checks are disabled
+    /// in CI".
+    pub disabled_in_ci: &'a str,
+    /// The reply to a requested check without a network, e.g. "Could not
+    /// check for updates: offline".
+    pub offline: &'a str,
+}
+
+impl Messages<'static> {
+    /// The English texts (the default).
+    pub const ENGLISH: Self = Self {
+        header: "A new version is available!",
+        latest_label: "Latest version:",
+        releases_behind: "releases behind",
+        changelog_label: "Changelog:",
+        and_more: "(and more...)",
+        warning: "Warning",
+        breaking_changes: "This release contains breaking changes",
+        upgrade_label: "Update with:",
+        more_info_label: "More info:",
+        available: "available",
+        you_have: "you have",
+        up_to_date: "You're already using the latest version!",
+        disabled: "Update checks are disabled",
+        disabled_by: "Update checks are disabled by",
+        disabled_in_ci: "Update checks are disabled in CI",
+        offline: "Could not check for updates: offline",
+    };
+
+    /// The German texts.
+    pub const GERMAN: Self = Self {
+        header: "Eine neue Version ist verfügbar!",
+        latest_label: "Neueste Version:",
+        releases_behind: "Versionen zurück",
+        changelog_label: "Änderungen:",
+        and_more: "(und mehr...)",
+        warning: "Warnung",
+        breaking_changes: "Diese Version enthält inkompatible Änderungen",
+        upgrade_label: "Aktualisieren mit:",
+        more_info_label: "Weitere Infos:",
+        available: "verfügbar",
+        you_have: "du hast",
+        up_to_date: "Du verwendest bereits die neueste Version!",
+        disabled: "Update-Prüfungen sind deaktiviert",
+        disabled_by: "Update-Prüfungen sind deaktiviert durch",
+        disabled_in_ci: "Update-Prüfungen sind in CI deaktiviert",
+        offline: "Keine Update-Prüfung möglich: offline",
+    };
+
+    /// The French texts.
+    pub const FRENCH: Self = Self {
+        header: "Une nouvelle version est disponible !",
+        latest_label: "Dernière version :",
+        releases_behind: "versions de retard",
+        changelog_label: "Notes de version :",
+        and_more: "(et plus...)",
+        warning: "Attention",
+        breaking_changes: "Cette version contient des changements incompatibles",
+        upgrade_label: "Mettre à jour avec :",
+        more_info_label: "Plus d'infos :",
+        available: "disponible",
+        you_have: "vous avez",
+        up_to_date: "Vous utilisez déjà la dernière version !",
+        disabled: "Les vérifications de mise à jour sont désactivées",
+        disabled_by: "Les vérifications de mise à jour sont désactivées par",
+        disabled_in_ci: "Les vérifications de mise à jour sont désactivées en CI",
+        offline: "Impossible de vérifier les mises à jour : hors ligne",
+    };
+
+    /// The Japanese texts.
+    pub const JAPANESE: Self = Self {
+        header: "新しいバージョンが利用可能です！",
+        latest_label: "最新バージョン:",
+        releases_behind: "リリース遅れ",
+        changelog_label: "変更履歴:",
+        and_more: "(ほか...)",
+        warning: "警告",
+        breaking_changes: "このリリースには互換性のない変更が含まれています",
+        upgrade_label: "更新コマンド:",
+        more_info_label: "詳細:",
+        available: "が利用可能です",
+        you_have: "現在",
+        up_to_date: "すでに最新バージョンを使用しています！",
+        disabled: "更新チェックは無効です",
+        disabled_by: "更新チェックは次の変数で無効です:",
+        disabled_in_ci: "CIでは更新チェックは無効です",
+        offline: "更新を確認できません: オフライン",
+    };
+
+    /// Returns the built-in texts for a locale like `de`, `fr-CA` or
+    /// `ja_JP.UTF-8`, or `None` if the language is not translated.
+    #[must_use]
+    pub fn for_locale(locale: &str) -> Option<Self> {
+        let language = locale
+            .split(['-', '_', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match language.as_str() {
+            "en" => Some(Self::ENGLISH),
+            "de" => Some(Self::GERMAN),
+            "fr" => Some(Self::FRENCH),
+            "ja" => Some(Self::JAPANESE),
+            _ => None,
+        }
+    }
+
+    /// Returns the built-in texts for the locale of the environment.
+    ///
+    /// Reads the first non-empty variable of `LC_ALL`, `LC_MESSAGES` and
+    /// `LANG`, like gettext. Returns `None` if none is set or the language is
+    /// not translated.
+    #[must_use]
+    pub fn from_env() -> Option<Self> {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(|variable| std::env::var(variable).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|locale| Self::for_locale(&locale))
+    }
+}
+
+impl<'a> Messages<'a> {
+    /// Replaces the headline, e.g. with "my-tool 2.0.0 is out!".
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::{check_crates_io, DisplayStyle, Messages};
+    ///
+    /// if let Ok(info) = check_crates_io("serde", "1.0.0") {
+    ///     let header = format!("{} {} is out!", info.name, info.latest_version);
+    ///     let messages = Messages::default()
+    ///         .header(&header)
+    ///         .more_info_label("Release notes:");
+    ///     println!("{}", info.styled(DisplayStyle::Emoji).messages(messages));
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn header(mut self, header: &'a str) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Replaces the label before the versions.
+    #[must_use]
+    pub const fn latest_label(mut self, label: &'a str) -> Self {
+        self.latest_label = label;
+        self
+    }
+
+    /// Replaces the label before the changelog.
+    #[must_use]
+    pub const fn changelog_label(mut self, label: &'a str) -> Self {
+        self.changelog_label = label;
+        self
+    }
+
+    /// Replaces the label before the URL.
+    #[must_use]
+    pub const fn more_info_label(mut self, label: &'a str) -> Self {
+        self.more_info_label = label;
+        self
+    }
+}
+
+impl Default for Messages<'_> {
+    fn default() -> Self {
+        Messages::ENGLISH
+    }
+}
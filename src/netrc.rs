@@ -0,0 +1,108 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+use std::path::PathBuf;
+
+/// Returns the `Authorization` header for `host` from the user's `.netrc`,
+/// if it has credentials for the host.
+///
+/// The file is read from `NETRC`, or `~/.netrc` (`~/_netrc` on Windows), on
+/// every call so edits apply to the next check.
+pub(crate) fn authorization(host: &str) -> Option<String> {
+    let contents = std::fs::read_to_string(path()?).ok()?;
+    let (login, password) = credentials(&contents, host)?;
+    Some(crate::http::basic_authorization(&login, &password))
+}
+
+/// Returns the path of the `.netrc` file, honoring `NETRC` like curl.
+fn path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os("NETRC").filter(|path| !path.is_empty()) {
+        return Some(PathBuf::from(path));
+    }
+    let file = if cfg!(windows) { "_netrc" } else { ".netrc" };
+    std::env::home_dir().map(|home| home.join(file))
+}
+
+/// Returns the login and password for `host` from the contents of a
+/// `.netrc` file.
+///
+/// The first `machine` entry matching the host case-insensitively and
+/// holding a password wins. The `default` entry is ignored, so credentials
+/// are only sent to hosts they were written down for, and `macdef` macros are
+/// skipped.
+pub(crate) fn credentials(contents: &str, host: &str) -> Option<(String, String)> {
+    let mut matches = false;
+    let mut login: Option<&str> = None;
+    let mut password: Option<&str> = None;
+    let mut tokens = Tokens { rest: contents };
+    while let Some(token) = tokens.next() {
+        match token {
+            "machine" | "default" => {
+                if matches && let Some(password) = password {
+                    return Some((login.unwrap_or_default().to_owned(), password.to_owned()));
+                }
+                matches = token == "machine"
+                    && tokens
+                        .next()
+                        .is_some_and(|machine| machine.eq_ignore_ascii_case(host));
+                login = None;
+                password = None;
+            }
+            "login" => login = tokens.next(),
+            "password" => password = tokens.next(),
+            "account" => {
+                tokens.next();
+            }
+            "macdef" => tokens.skip_macro(),
+            _ => {}
+        }
+    }
+    password
+        .filter(|_| matches)
+        .map(|password| (login.unwrap_or_default().to_owned(), password.to_owned()))
+}
+
+/// The whitespace-separated tokens of a `.netrc` file, with support for
+/// double-quoted tokens and skipping macro definitions.
+struct Tokens<'a> {
+    rest: &'a str,
+}
+
+impl Tokens<'_> {
+    /// Skips the body of a `macdef`, which ends at the next empty line.
+    fn skip_macro(&mut self) {
+        self.rest = self
+            .rest
+            .split_once("\n\n")
+            .or_else(|| self.rest.split_once("\r\n\r\n"))
+            .map_or("", |(_, rest)| rest);
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        loop {
+            self.rest = self.rest.trim_start();
+            if self.rest.starts_with('#') {
+                self.rest = self.rest.split_once('\n').map_or("", |(_, rest)| rest);
+            } else {
+                break;
+            }
+        }
+        if self.rest.is_empty() {
+            return None;
+        }
+        if let Some(quoted) = self.rest.strip_prefix('"') {
+            let (token, rest) = quoted.split_once('"').unwrap_or((quoted, ""));
+            self.rest = rest;
+            return Some(token);
+        }
+        let end = self
+            .rest
+            .find(char::is_whitespace)
+            .unwrap_or(self.rest.len());
+        let (token, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(token)
+    }
+}
@@ -0,0 +1,130 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+
+/// Returns whether release notes announce breaking changes.
+///
+/// Looks for `BREAKING` and "breaking change" in any case, but not
+/// "non-breaking change", and for conventional-commit subjects marked with
+/// `!`, such as `feat!: drop the old API` or `fix(parser)!: reject tabs`.
+pub(crate) fn has_breaking_changes(notes: &str) -> bool {
+    let lowercase = notes.to_ascii_lowercase();
+    notes
+        .match_indices("BREAKING")
+        .chain(lowercase.match_indices("breaking change"))
+        .chain(lowercase.match_indices("breaking-change"))
+        .any(|(index, _)| {
+            !lowercase[..index]
+                .trim_end_matches(['-', ' '])
+                .ends_with("non")
+        })
+        || notes
+            .lines()
+            .filter_map(conventional_commit)
+            .any(|commit| commit.breaking)
+}
+
+/// A line of release notes in the conventional-commit format.
+pub(crate) struct ConventionalCommit<'a> {
+    /// The type of the commit, e.g. `feat` or `fix`.
+    pub(crate) kind: &'a str,
+    /// The optional scope in parentheses, e.g. `parser`.
+    pub(crate) scope: Option<&'a str>,
+    /// Whether the commit is marked as breaking with `!`.
+    pub(crate) breaking: bool,
+    /// The text after the colon.
+    pub(crate) description: &'a str,
+}
+
+/// Parses a line like `- feat(scope)!: description`, ignoring list bullets.
+///
+/// Returns `None` if the line is not a conventional commit.
+pub(crate) fn conventional_commit(line: &str) -> Option<ConventionalCommit<'_>> {
+    let line = line
+        .trim_start()
+        .trim_start_matches(['-', '*', '+'])
+        .trim_start();
+    let (header, description) = line.split_once(':')?;
+    let (header, breaking) = header
+        .strip_suffix('!')
+        .map_or((header, false), |header| (header, true));
+    let (kind, scope) = match header.split_once('(') {
+        Some((kind, scope)) => match scope.strip_suffix(')') {
+            Some(scope) if !scope.contains(['(', ')', ' ']) => (kind, Some(scope)),
+            _ => return None,
+        },
+        None => (header, None),
+    };
+    (!kind.is_empty() && kind.chars().all(|c| c.is_ascii_alphabetic())).then_some(
+        ConventionalCommit {
+            kind,
+            scope,
+            breaking,
+            description: description.trim(),
+        },
+    )
+}
+
+/// Regroups release notes written as conventional commits into sections.
+///
+/// Breaking changes come first, followed by features, fixes and all other
+/// lines, each under a markdown heading. Existing headings are dropped.
+/// Returns `None` if no line is a conventional commit.
+pub(crate) fn group_conventional_commits(notes: &str) -> Option<String> {
+    let mut breaking = Vec::new();
+    let mut features = Vec::new();
+    let mut fixes = Vec::new();
+    let mut other = Vec::new();
+    let mut found = false;
+    for line in notes.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some(commit) = conventional_commit(line) else {
+            let footer = line
+                .strip_prefix("BREAKING CHANGE:")
+                .or_else(|| line.strip_prefix("BREAKING-CHANGE:"));
+            match footer {
+                Some(description) => breaking.push(description.trim().to_owned()),
+                None => other.push(line.trim_start_matches(['-', '*', '+', ' ']).to_owned()),
+            }
+            continue;
+        };
+        found = true;
+        let entry = commit.scope.map_or_else(
+            || commit.description.to_owned(),
+            |scope| format!("{scope}: {}", commit.description),
+        );
+        if commit.breaking {
+            breaking.push(entry);
+        } else if commit.kind.eq_ignore_ascii_case("feat") {
+            features.push(entry);
+        } else if commit.kind.eq_ignore_ascii_case("fix") {
+            fixes.push(entry);
+        } else {
+            other.push(entry);
+        }
+    }
+    if !found {
+        return None;
+    }
+
+    let mut grouped = String::new();
+    for (heading, entries) in [
+        ("Breaking changes", breaking),
+        ("Features", features),
+        ("Fixes", fixes),
+        ("Other changes", other),
+    ] {
+        if entries.is_empty() {
+            continue;
+        }
+        grouped.push_str("### ");
+        grouped.push_str(heading);
+        grouped.push('\n');
+        for entry in entries {
+            grouped.push_str("- ");
+            grouped.push_str(&entry);
+            grouped.push('\n');
+        }
+    }
+    Some(grouped)
+}
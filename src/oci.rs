@@ -0,0 +1,156 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+use semver::Version;
+use serde::Deserialize;
+
+use crate::data::{ReleaseInfo, parse_tag_version};
+
+/// Response structure for the OCI distribution `tags/list` API.
+#[derive(Deserialize)]
+struct TagsResponse {
+    #[serde(default)]
+    tags: Option<Vec<String>>,
+}
+
+/// Response structure of a registry token service.
+#[derive(Deserialize)]
+pub(crate) struct TokenResponse {
+    token: Option<String>,
+    access_token: Option<String>,
+}
+
+impl TokenResponse {
+    /// Returns the issued token; registries use either field name.
+    pub(crate) fn into_token(self) -> Option<String> {
+        self.token.or(self.access_token)
+    }
+}
+
+/// A bearer challenge from a `WWW-Authenticate` header.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct BearerChallenge {
+    pub(crate) realm: String,
+    pub(crate) service: Option<String>,
+    pub(crate) scope: Option<String>,
+}
+
+impl BearerChallenge {
+    /// Parses a header such as
+    /// `Bearer realm="https://ghcr.io/token",service="ghcr.io",scope="repository:user/image:pull"`.
+    ///
+    /// Returns `None` if the header is not a bearer challenge with a realm.
+    pub(crate) fn parse(header: &str) -> Option<Self> {
+        let (scheme, params) = header.trim().split_once(' ')?;
+        if !scheme.eq_ignore_ascii_case("bearer") {
+            return None;
+        }
+        let mut realm = None;
+        let mut service = None;
+        let mut scope = None;
+        let mut rest = params.trim();
+        while let Some((key, value)) = rest.split_once('=') {
+            let key = key.trim().trim_start_matches(',').trim();
+            let (value, remaining) = if let Some(quoted) = value.strip_prefix('"') {
+                quoted.split_once('"')?
+            } else {
+                value.split_once(',').unwrap_or((value, ""))
+            };
+            match key.to_ascii_lowercase().as_str() {
+                "realm" => realm = Some(value.to_owned()),
+                "service" => service = Some(value.to_owned()),
+                "scope" => scope = Some(value.to_owned()),
+                _ => {}
+            }
+            rest = remaining;
+        }
+        Some(Self {
+            realm: realm?,
+            service,
+            scope,
+        })
+    }
+
+    /// Returns the URL requesting a pull token for the repository `name`.
+    pub(crate) fn token_url(&self, name: &str) -> String {
+        let scope = self
+            .scope
+            .clone()
+            .unwrap_or_else(|| format!("repository:{name}:pull"));
+        let separator = if self.realm.contains('?') { '&' } else { '?' };
+        let service = self
+            .service
+            .as_ref()
+            .map(|service| format!("service={service}&"))
+            .unwrap_or_default();
+        format!("{}{separator}{service}scope={scope}", self.realm)
+    }
+}
+
+/// Returns the target of a `Link: <...>; rel="next"` header, resolved
+/// against the registry URL.
+pub(crate) fn next_link(header: &str, registry: &str) -> Option<String> {
+    let (target, params) = header.split_once(';')?;
+    if !params.replace(' ', "").contains("rel=\"next\"") {
+        return None;
+    }
+    let target = target.trim().strip_prefix('<')?.strip_suffix('>')?;
+    if target.starts_with('/') {
+        Some(format!("{}{target}", registry.trim_end_matches('/')))
+    } else {
+        Some(target.to_owned())
+    }
+}
+
+/// Parses a `tags/list` response, returning the tags.
+pub(crate) fn parse_tags(json: &str) -> anyhow::Result<Vec<String>> {
+    let response: TagsResponse = serde_json::from_str(json)
+        .map_err(|e| anyhow::anyhow!("Failed to parse registry tags: {e}"))?;
+    Ok(response.tags.unwrap_or_default())
+}
+
+impl ReleaseInfo {
+    /// Creates a `ReleaseInfo` from the tags of an OCI image.
+    ///
+    /// Every tag that parses as a version (ignoring a leading `v`) is
+    /// collected, and the highest one becomes the latest version. Tags with a
+    /// pre-release or build suffix, such as `1.2.0-alpine` image variants,
+    /// are skipped. The upgrade command pulls the image with the latest tag.
+    ///
+    /// # Arguments
+    ///
+    /// * `tags` - The tags of the image
+    /// * `registry` - The registry URL, e.g. `https://ghcr.io`
+    /// * `name` - The repository name, e.g. `user/image`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no tag is a version.
+    pub(crate) fn from_oci_tags(
+        tags: &[String],
+        registry: &str,
+        name: &str,
+    ) -> anyhow::Result<Self> {
+        let mut tags: Vec<(Version, &str)> = tags
+            .iter()
+            .filter_map(|tag| {
+                let version = parse_tag_version(tag)?;
+                (version.pre.is_empty() && version.build.is_empty())
+                    .then_some((version, tag.as_str()))
+            })
+            .collect();
+        tags.sort();
+        tags.dedup_by(|a, b| a.0 == b.0);
+        let registry = registry.trim_end_matches('/');
+        let (version, tag) = tags
+            .last()
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("No version tag found for {name} on {registry}"))?;
+        let host = registry
+            .split_once("://")
+            .map_or(registry, |(_, host)| host);
+        Ok(Self {
+            versions: tags.into_iter().map(|(version, _)| version).collect(),
+            upgrade_command: Some(format!("docker pull {host}/{name}:{tag}")),
+            ..Self::new(version, format!("{registry}/{name}"))
+        })
+    }
+}
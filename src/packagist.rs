@@ -0,0 +1,78 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+use semver::Version;
+use serde::Deserialize;
+
+use crate::data::{ReleaseInfo, parse_tag_version};
+
+/// Response structure for the Packagist metadata API (`/p2/{vendor}/{package}.json`).
+#[derive(Deserialize)]
+struct PackagistResponse {
+    packages: std::collections::HashMap<String, Vec<PackagistVersion>>,
+}
+
+/// A published version of a Composer package.
+///
+/// The metadata is minified, so fields equal to the previous version are
+/// left out; only the version itself is always present.
+#[derive(Deserialize)]
+struct PackagistVersion {
+    version: String,
+}
+
+/// Returns the Packagist metadata URL of a `vendor/package` name.
+///
+/// # Errors
+///
+/// Returns an error if the name is not of the form `vendor/package`.
+pub(crate) fn metadata_url(name: &str) -> anyhow::Result<String> {
+    name.split_once('/')
+        .filter(|(vendor, package)| {
+            !vendor.is_empty() && !package.is_empty() && !package.contains('/')
+        })
+        .ok_or_else(|| {
+            anyhow::anyhow!("Expected a Packagist package in the form VENDOR/PACKAGE, got '{name}'")
+        })?;
+    Ok(format!(
+        "https://repo.packagist.org/p2/{}.json",
+        name.to_lowercase()
+    ))
+}
+
+impl ReleaseInfo {
+    /// Creates a `ReleaseInfo` from a Packagist metadata response.
+    ///
+    /// Every release that parses as a version (ignoring a leading `v`) is
+    /// collected, and the highest one becomes the latest version. The upgrade
+    /// command is `composer global update <name>`.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The response of `https://repo.packagist.org/p2/{name}.json`
+    /// * `name` - The `vendor/package` name
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the response cannot be parsed or lists no version.
+    pub(crate) fn from_packagist(json: &str, name: &str) -> anyhow::Result<Self> {
+        let name = name.to_lowercase();
+        let mut response: PackagistResponse = serde_json::from_str(json)
+            .map_err(|e| anyhow::anyhow!("Failed to parse Packagist response: {e}"))?;
+        let mut versions: Vec<Version> = response
+            .packages
+            .remove(&name)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|release| parse_tag_version(&release.version))
+            .collect();
+        versions.sort();
+        let version = versions
+            .last()
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("No version of {name} found on Packagist"))?;
+        Ok(Self {
+            versions,
+            upgrade_command: Some(format!("composer global update {name}")),
+            ..Self::new(version, format!("https://packagist.org/packages/{name}"))
+        })
+    }
+}
@@ -0,0 +1,109 @@
+use semver::VersionReq;
+
+use crate::data::{UpdateInfo, UpdateKind};
+
+/// A policy deciding which updates are reported.
+///
+/// Apply it to the result of a check with [`UpdateInfo::with_policy`].
+///
+/// # Examples
+///
+/// ```rust
+/// use semver::VersionReq;
+/// use update_available::{NotifyPolicy, UpdateKind, check_crates_io};
+///
+/// // Only notify about minor updates within the 1.x series, ignoring patch releases
+/// let policy = NotifyPolicy::new()
+///     .requirement(VersionReq::parse("^1").unwrap())
+///     .notify_at_least(UpdateKind::Minor);
+/// if let Ok(info) = check_crates_io("serde", "1.0.0") {
+///     info.with_policy(&policy).print();
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NotifyPolicy {
+    requirement: Option<VersionReq>,
+    min_kind: Option<UpdateKind>,
+}
+
+impl NotifyPolicy {
+    /// Creates a policy that reports every update.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            requirement: None,
+            min_kind: None,
+        }
+    }
+
+    /// Only reports updates to versions matching the requirement.
+    ///
+    /// If the latest version does not match, the newest matching version
+    /// between the current and the latest version is reported instead, e.g.
+    /// `^1` reports `1.5.0` rather than `2.0.0`.
+    #[must_use]
+    pub fn requirement(mut self, requirement: VersionReq) -> Self {
+        self.requirement = Some(requirement);
+        self
+    }
+
+    /// Only reports updates at least as severe as the given kind.
+    ///
+    /// For example, `notify_at_least(UpdateKind::Minor)` ignores patch
+    /// releases and pre-releases.
+    #[must_use]
+    pub const fn notify_at_least(mut self, kind: UpdateKind) -> Self {
+        self.min_kind = Some(kind);
+        self
+    }
+}
+
+impl UpdateInfo {
+    /// Applies a notification policy to the update information.
+    ///
+    /// If the latest version is not allowed by the policy, the newest allowed
+    /// version from [`UpdateInfo::versions_behind`] becomes the latest version
+    /// and the changelog and upgrade command, which describe the disallowed
+    /// release, are removed.
+    /// If no version is allowed, no update is reported and the latest version
+    /// is set to the current version.
+    ///
+    /// Updates less severe than the policy's minimum kind are not reported,
+    /// but keep their latest version and [`UpdateInfo::update_kind`].
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The policy deciding which updates are reported
+    #[must_use]
+    pub fn with_policy(mut self, policy: &NotifyPolicy) -> Self {
+        if let Some(requirement) = &policy.requirement {
+            self.restrict_to(requirement);
+        }
+        if let (Some(min_kind), Some(kind)) = (policy.min_kind, self.update_kind)
+            && kind < min_kind
+        {
+            self.is_update_available = false;
+        }
+        self
+    }
+
+    /// Replaces the latest version with the newest version matching the requirement.
+    fn restrict_to(&mut self, requirement: &VersionReq) {
+        if !self.is_update_available || requirement.matches(&self.latest_version) {
+            return;
+        }
+        let latest = self
+            .versions_behind
+            .iter()
+            .filter(|version| requirement.matches(version))
+            .max()
+            .cloned()
+            .unwrap_or_else(|| self.current_version.clone());
+        self.versions_behind.retain(|version| *version <= latest);
+        self.update_kind = UpdateKind::classify(&self.current_version, &latest);
+        self.is_update_available = self.update_kind.is_some();
+        self.latest_version = latest;
+        self.changelog = None;
+        self.upgrade_command = None;
+    }
+}
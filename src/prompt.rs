@@ -0,0 +1,73 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+use std::{
+    io::{self, BufRead, IsTerminal as _, Write},
+    process::{Command, ExitStatus},
+};
+
+use crate::data::UpdateInfo;
+
+impl UpdateInfo {
+    /// Prints the update notification and offers to run an upgrade command.
+    ///
+    /// If an update is available, the notification is printed and the user
+    /// is asked "Update now? [y/N]". The command is only run if they answer
+    /// `y` or `yes`. Nothing is asked if stdin or stdout is not a terminal,
+    /// e.g. in scripts or CI, so the prompt never blocks unattended runs.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The upgrade command, e.g. `cargo install my-tool`
+    ///
+    /// # Returns
+    ///
+    /// Returns the exit status of the upgrade command, or `None` if it was
+    /// not run.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading the answer fails or the command cannot be
+    /// started.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use std::process::Command;
+    /// use update_available::check_crates_io;
+    ///
+    /// if let Ok(info) = check_crates_io("my-tool", env!("CARGO_PKG_VERSION")) {
+    ///     let mut upgrade = Command::new("cargo");
+    ///     upgrade.args(["install", "my-tool", "--locked"]);
+    ///     match info.prompt_and_run(&mut upgrade) {
+    ///         Ok(Some(status)) if status.success() => println!("Updated, please restart"),
+    ///         Ok(Some(status)) => eprintln!("The update failed: {status}"),
+    ///         Ok(None) => {}
+    ///         Err(e) => eprintln!("Failed to run the update: {e}"),
+    ///     }
+    /// }
+    /// ```
+    pub fn prompt_and_run(&self, command: &mut Command) -> io::Result<Option<ExitStatus>> {
+        if !self.is_update_available {
+            return Ok(None);
+        }
+        self.print();
+        if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+            return Ok(None);
+        }
+        if !confirm(&mut io::stdin().lock(), &mut io::stdout())? {
+            return Ok(None);
+        }
+        command.status().map(Some)
+    }
+}
+
+/// Asks "Update now? [y/N]" and returns whether the answer was `y` or `yes`.
+///
+/// Any other answer, including an empty one or the end of the input, is a no.
+pub(crate) fn confirm(input: &mut impl BufRead, output: &mut impl Write) -> io::Result<bool> {
+    write!(output, "Update now? [y/N] ")?;
+    output.flush()?;
+    let mut answer = String::new();
+    input.read_line(&mut answer)?;
+    let answer = answer.trim();
+    Ok(answer.eq_ignore_ascii_case("y") || answer.eq_ignore_ascii_case("yes"))
+}
@@ -0,0 +1,61 @@
+use semver::Version;
+use serde::Deserialize;
+
+use crate::data::{ReleaseInfo, parse_loose_version};
+
+/// A package of a project in the Repology API response.
+#[derive(Deserialize)]
+struct RepologyPackage {
+    version: String,
+    #[serde(default)]
+    status: String,
+}
+
+impl ReleaseInfo {
+    /// Creates a `ReleaseInfo` from a Repology project response.
+    ///
+    /// The highest version that Repology marks as the newest stable release
+    /// (status `newest` or `unique`) becomes the latest version, falling back
+    /// to the highest version packaged anywhere. All distinct versions across
+    /// the packagings are listed in [`ReleaseInfo::versions`], so downstream
+    /// packagings that lag behind or run ahead can be spotted.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The response of `https://repology.org/api/v1/project/<name>`
+    /// * `name` - The Repology project name
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the response cannot be parsed or no packaging has
+    /// a version.
+    pub(crate) fn from_repology(json: &str, name: &str) -> anyhow::Result<Self> {
+        let packages: Vec<RepologyPackage> = serde_json::from_str(json)
+            .map_err(|e| anyhow::anyhow!("Failed to parse Repology response: {e}"))?;
+        let parsed: Vec<(Version, &str)> = packages
+            .iter()
+            .filter_map(|package| {
+                Some((
+                    parse_loose_version(&package.version)?,
+                    package.status.as_str(),
+                ))
+            })
+            .collect();
+        let version = parsed
+            .iter()
+            .filter(|(_, status)| matches!(*status, "newest" | "unique"))
+            .map(|(version, _)| version)
+            .max()
+            .or_else(|| parsed.iter().map(|(version, _)| version).max())
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("No version of {name} found on Repology"))?;
+        let mut versions: Vec<Version> = parsed.into_iter().map(|(version, _)| version).collect();
+        versions.sort();
+        versions.dedup();
+        let url = format!("https://repology.org/project/{name}/versions");
+        Ok(Self {
+            versions,
+            ..Self::new(version, url)
+        })
+    }
+}
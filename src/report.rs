@@ -0,0 +1,210 @@
+use core::fmt;
+
+use crate::data::{UpdateInfo, UpdateKind};
+
+/// Limits the number of requests a batch check sends at the same time.
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::{BatchLimits, Source, check_many_with_limits};
+///
+/// let limits = BatchLimits::new(4).per_host(2);
+/// let report = check_many_with_limits(
+///     &[("serde", "1.0.0"), ("anyhow", "1.0.0")],
+///     &Source::CratesIo,
+///     limits,
+/// );
+/// print!("{report}");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BatchLimits {
+    /// The maximum number of packages checked at the same time.
+    pub max_parallel: usize,
+    /// The maximum number of requests in flight to a single host, if limited.
+    pub per_host: Option<usize>,
+}
+
+impl BatchLimits {
+    /// The number of packages checked at the same time by default.
+    pub const DEFAULT_MAX_PARALLEL: usize = 8;
+
+    /// Creates limits checking at most `max_parallel` packages at the same time.
+    ///
+    /// A limit of 0 is treated as 1.
+    #[must_use]
+    pub const fn new(max_parallel: usize) -> Self {
+        Self {
+            max_parallel,
+            per_host: None,
+        }
+    }
+
+    /// Sends at most `limit` requests to a single host at the same time.
+    ///
+    /// Useful if the packages are checked on a source that queries several
+    /// hosts, such as [`crate::Source::chain`]. A limit of 0 is treated as 1.
+    #[must_use]
+    pub const fn per_host(mut self, limit: usize) -> Self {
+        self.per_host = Some(limit);
+        self
+    }
+}
+
+impl Default for BatchLimits {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_MAX_PARALLEL)
+    }
+}
+
+/// The result of checking a single package of a batch.
+pub struct PackageReport {
+    /// The name of the package, e.g. `serde` or `user/repo`.
+    pub name: String,
+    /// The current version the package was checked with.
+    pub current_version: String,
+    /// The update information, or the error that occurred while checking.
+    pub result: anyhow::Result<UpdateInfo>,
+}
+
+impl PackageReport {
+    /// Returns whether the package was checked and an update is available.
+    #[must_use]
+    pub fn is_update_available(&self) -> bool {
+        self.result
+            .as_ref()
+            .is_ok_and(|info| info.is_update_available)
+    }
+
+    /// Returns the kind of the update, or `None` if no update is available
+    /// or the check failed.
+    #[must_use]
+    pub fn update_kind(&self) -> Option<UpdateKind> {
+        self.result.as_ref().ok()?.update_kind
+    }
+}
+
+/// The results of checking many packages.
+///
+/// Created by [`crate::check_many`] and [`crate::check_github_batch`]. The
+/// `Display` implementation lists every outdated package as
+/// `name current -> latest` and every failed one as `name failed: error`.
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::{Source, check_many};
+///
+/// let report = check_many(&[("serde", "1.0.0"), ("anyhow", "1.0.0")], &Source::CratesIo);
+/// if report.any_updates() {
+///     print!("{report}");
+/// }
+/// ```
+#[derive(Default)]
+pub struct UpdateReport {
+    /// The result of each package, in the order the packages were given.
+    pub packages: Vec<PackageReport>,
+}
+
+impl UpdateReport {
+    /// Returns whether an update is available for any package.
+    #[must_use]
+    pub fn any_updates(&self) -> bool {
+        self.packages.iter().any(PackageReport::is_update_available)
+    }
+
+    /// Returns the packages for which an update is available.
+    pub fn outdated(&self) -> impl Iterator<Item = &PackageReport> {
+        self.packages
+            .iter()
+            .filter(|package| package.is_update_available())
+    }
+
+    /// Returns the packages that could not be checked.
+    pub fn failed(&self) -> impl Iterator<Item = &PackageReport> {
+        self.packages
+            .iter()
+            .filter(|package| package.result.is_err())
+    }
+
+    /// Removes outdated packages whose update is less severe than `kind`.
+    ///
+    /// Up-to-date and failed packages are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::{Source, UpdateKind, check_many};
+    ///
+    /// let report = check_many(&[("serde", "1.0.0"), ("anyhow", "1.0.0")], &Source::CratesIo)
+    ///     .exclude(["anyhow"])
+    ///     .min_kind(UpdateKind::Minor)
+    ///     .sort_by_severity();
+    /// print!("{report}");
+    /// ```
+    #[must_use]
+    pub fn min_kind(mut self, kind: UpdateKind) -> Self {
+        self.packages.retain(|package| {
+            !package.is_update_available() || package.update_kind().is_some_and(|k| k >= kind)
+        });
+        self
+    }
+
+    /// Removes the packages with the given names, e.g. ignored dependencies.
+    #[must_use]
+    pub fn exclude<S: AsRef<str>>(mut self, names: impl IntoIterator<Item = S>) -> Self {
+        let names: Vec<S> = names.into_iter().collect();
+        self.packages
+            .retain(|package| !names.iter().any(|name| name.as_ref() == package.name));
+        self
+    }
+
+    /// Sorts the packages by the severity of their update, most severe first.
+    ///
+    /// Up-to-date and failed packages come last. Packages of the same
+    /// severity keep their order.
+    #[must_use]
+    pub fn sort_by_severity(mut self) -> Self {
+        self.packages
+            .sort_by_key(|package| core::cmp::Reverse(package.update_kind()));
+        self
+    }
+
+    /// Sorts the packages by name.
+    #[must_use]
+    pub fn sort_by_name(mut self) -> Self {
+        self.packages.sort_by(|a, b| a.name.cmp(&b.name));
+        self
+    }
+
+    /// Returns the result of a package by name.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&PackageReport> {
+        self.packages.iter().find(|package| package.name == name)
+    }
+}
+
+impl FromIterator<PackageReport> for UpdateReport {
+    fn from_iter<I: IntoIterator<Item = PackageReport>>(iter: I) -> Self {
+        Self {
+            packages: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl fmt::Display for UpdateReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for package in &self.packages {
+            match &package.result {
+                Ok(info) if info.is_update_available => writeln!(
+                    f,
+                    "{} {} -> {}",
+                    package.name, package.current_version, info.latest_version
+                )?,
+                Ok(_) => {}
+                Err(e) => writeln!(f, "{} failed: {e}", package.name)?,
+            }
+        }
+        Ok(())
+    }
+}
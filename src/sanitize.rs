@@ -0,0 +1,137 @@
+use crate::data::UpdateInfo;
+
+/// Converts markdown/HTML release notes into clean plain text.
+///
+/// This is useful for embedding a changelog in desktop notifications or
+/// logs, where markup would show up verbatim. The following is removed:
+/// * HTML tags and comments (`<br>` becomes a line break)
+/// * Images, links and autolinks (keeping their text)
+/// * Heading markers, bold, italic and strikethrough markers and backticks
+/// * Common HTML entities are decoded
+///
+/// List markers are kept, and runs of blank lines are collapsed into one.
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::strip_markup;
+///
+/// let notes = "## Fixes\n- **Fixed** a [bug](https://example.com/1)<br>";
+/// assert_eq!(strip_markup(notes), "Fixes\n- Fixed a bug");
+/// ```
+#[must_use]
+pub fn strip_markup(text: &str) -> String {
+    let text = strip_html(text);
+    let mut output = String::with_capacity(text.len());
+    let mut blank = true;
+    for line in text.lines() {
+        let line = strip_inline(line.trim_start_matches('#').trim());
+        let line = line.trim_end();
+        if line.is_empty() {
+            if !blank {
+                output.push('\n');
+            }
+            blank = true;
+            continue;
+        }
+        output.push_str(line);
+        output.push('\n');
+        blank = false;
+    }
+    output.trim_end().to_owned()
+}
+
+/// Removes HTML comments and tags and decodes common entities.
+///
+/// Autolinks such as `<https://example.com>` keep their URL.
+fn strip_html(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('<') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[1..end];
+        if tag.starts_with("http://") || tag.starts_with("https://") {
+            output.push_str(tag);
+        } else if tag.starts_with(|c: char| c.is_ascii_alphabetic() || c == '/' || c == '!') {
+            let name = tag.trim_start_matches('/').to_ascii_lowercase();
+            if name.starts_with("br") || name == "p" {
+                output.push('\n');
+            }
+        } else {
+            output.push_str(&rest[..=end]);
+        }
+        rest = &rest[end + 1..];
+    }
+    output.push_str(rest);
+    output
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Removes inline markdown from a single line.
+fn strip_inline(line: &str) -> String {
+    let mut output = String::with_capacity(line.len());
+    let mut rest = line;
+    // Keep a list marker at the start of the line.
+    if let Some(text) = rest.strip_prefix("* ") {
+        output.push_str("* ");
+        rest = text;
+    }
+    while let Some(index) = rest.find(['!', '[', '*', '_', '~', '`']) {
+        output.push_str(&rest[..index]);
+        rest = &rest[index..];
+        if let Some((text, after)) = split_link(rest.strip_prefix('!').unwrap_or(rest)) {
+            output.push_str(&strip_inline(text));
+            rest = after;
+        } else if let Some(after) = ["**", "__", "~~"]
+            .iter()
+            .find_map(|marker| rest.strip_prefix(marker))
+        {
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix('`') {
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix('*')
+            && (after.starts_with(|c: char| !c.is_whitespace())
+                || output.ends_with(|c: char| !c.is_whitespace()))
+        {
+            rest = after;
+        } else {
+            let len = rest.chars().next().map_or(1, char::len_utf8);
+            output.push_str(&rest[..len]);
+            rest = &rest[len..];
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Splits a `[text](url)` link at the start of `text` into its text and the rest.
+fn split_link(text: &str) -> Option<(&str, &str)> {
+    let inner = text.strip_prefix('[')?;
+    let close = inner.find("](")?;
+    let after = &inner[close + 2..];
+    let end = after.find(')')?;
+    Some((&inner[..close], &after[end + 1..]))
+}
+
+impl UpdateInfo {
+    /// Returns the changelog converted to plain text, if there is one.
+    ///
+    /// See [`strip_markup`] for what is removed.
+    #[must_use]
+    pub fn plain_changelog(&self) -> Option<String> {
+        self.changelog.as_deref().map(strip_markup)
+    }
+}
@@ -0,0 +1,391 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use semver::{Version, VersionReq};
+use serde::Deserialize;
+
+use crate::data::UpdateInfo;
+
+/// The lockfile source identifier of crates.io.
+const CRATES_IO_SOURCE: &str = "registry+https://github.com/rust-lang/crates.io-index";
+
+/// The subset of a `Cargo.toml` manifest needed for scanning.
+#[derive(Deserialize)]
+struct Manifest {
+    package: Option<ManifestPackage>,
+    workspace: Option<ManifestWorkspace>,
+    #[serde(default)]
+    dependencies: BTreeMap<String, DependencySpec>,
+    #[serde(default, rename = "dev-dependencies")]
+    dev_dependencies: BTreeMap<String, DependencySpec>,
+    #[serde(default, rename = "build-dependencies")]
+    build_dependencies: BTreeMap<String, DependencySpec>,
+    #[serde(default)]
+    target: BTreeMap<String, TargetDependencies>,
+}
+
+/// The dependency tables of a `[target.'cfg(..)']` table.
+///
+/// Dependencies of every target are scanned, regardless of the platform.
+#[derive(Deserialize)]
+struct TargetDependencies {
+    #[serde(default)]
+    dependencies: BTreeMap<String, DependencySpec>,
+    #[serde(default, rename = "dev-dependencies")]
+    dev_dependencies: BTreeMap<String, DependencySpec>,
+    #[serde(default, rename = "build-dependencies")]
+    build_dependencies: BTreeMap<String, DependencySpec>,
+}
+
+/// The `[package]` table of a manifest.
+#[derive(Deserialize)]
+struct ManifestPackage {
+    name: String,
+}
+
+/// The `[workspace]` table of a manifest.
+#[derive(Deserialize)]
+struct ManifestWorkspace {
+    #[serde(default)]
+    members: Vec<String>,
+    #[serde(default)]
+    exclude: Vec<String>,
+    #[serde(default)]
+    dependencies: BTreeMap<String, DependencySpec>,
+}
+
+/// A dependency entry, either `name = "1.0"` or `name = { ... }`.
+#[derive(Deserialize, Clone)]
+#[serde(untagged)]
+enum DependencySpec {
+    Simple(String),
+    Detailed(DetailedDependency),
+}
+
+/// The detailed form of a dependency entry.
+#[derive(Deserialize, Clone)]
+struct DetailedDependency {
+    version: Option<String>,
+    path: Option<String>,
+    git: Option<String>,
+    registry: Option<String>,
+    package: Option<String>,
+    #[serde(default)]
+    workspace: bool,
+}
+
+/// The subset of a `Cargo.lock` file needed for scanning.
+#[derive(Deserialize)]
+struct Lockfile {
+    #[serde(default)]
+    package: Vec<LockedPackage>,
+}
+
+/// A resolved package in `Cargo.lock`.
+#[derive(Deserialize)]
+struct LockedPackage {
+    name: String,
+    version: Version,
+    source: Option<String>,
+}
+
+/// A workspace member together with its crates.io dependencies.
+pub(crate) struct Member {
+    pub(crate) name: String,
+    pub(crate) manifest_path: PathBuf,
+    pub(crate) dependencies: Vec<Dependency>,
+}
+
+/// A crates.io dependency and the version currently resolved in the lockfile.
+pub(crate) struct Dependency {
+    pub(crate) name: String,
+    pub(crate) current_version: Version,
+}
+
+/// The result of checking a single dependency of a workspace member.
+pub struct DependencyReport {
+    /// The crates.io name of the dependency.
+    pub name: String,
+    /// The version currently resolved in `Cargo.lock`.
+    pub current_version: Version,
+    /// The update information, or the error that occurred while checking.
+    pub result: anyhow::Result<UpdateInfo>,
+}
+
+/// The results for a single workspace member.
+pub struct MemberReport {
+    /// The package name of the member.
+    pub name: String,
+    /// The path to the member's `Cargo.toml`.
+    pub manifest_path: PathBuf,
+    /// The results for each crates.io dependency of the member.
+    pub dependencies: Vec<DependencyReport>,
+}
+
+/// The results of scanning a workspace, grouped by member.
+pub struct WorkspaceReport {
+    /// The results for each member of the workspace.
+    pub members: Vec<MemberReport>,
+}
+
+/// Collects all workspace members and their crates.io dependencies.
+///
+/// Dependencies of `[target.'cfg(..)']` tables are included for every target.
+/// Path, git and alternate registry dependencies are skipped, as are
+/// dependencies that are not resolved in the shared `Cargo.lock`.
+///
+/// # Errors
+///
+/// Returns an error if a manifest or the lockfile cannot be read or parsed.
+pub(crate) fn collect_members(manifest_path: &Path) -> anyhow::Result<Vec<Member>> {
+    let root_dir = manifest_path.parent().unwrap_or_else(|| Path::new("."));
+    let root = read_manifest(manifest_path)?;
+    let locked = read_lockfile(&root_dir.join("Cargo.lock"))?;
+    let workspace_deps = root
+        .workspace
+        .as_ref()
+        .map(|workspace| workspace.dependencies.clone())
+        .unwrap_or_default();
+
+    let mut members = Vec::new();
+    if root.package.is_some() {
+        members.push(member_from_manifest(
+            root_dir,
+            &root,
+            &workspace_deps,
+            &locked,
+        ));
+    }
+    if let Some(workspace) = &root.workspace {
+        for dir in member_dirs(root_dir, workspace)? {
+            let manifest = read_manifest(&dir.join("Cargo.toml"))?;
+            members.push(member_from_manifest(
+                &dir,
+                &manifest,
+                &workspace_deps,
+                &locked,
+            ));
+        }
+    }
+    Ok(members.into_iter().flatten().collect())
+}
+
+/// Scans a workspace and checks every crates.io dependency for updates.
+///
+/// Each distinct dependency version is only checked once, even if it is
+/// shared by several members.
+///
+/// # Errors
+///
+/// Returns an error if a manifest or the lockfile cannot be read or parsed.
+#[cfg(feature = "blocking")]
+pub(crate) fn scan(manifest_path: &Path) -> anyhow::Result<WorkspaceReport> {
+    let members = collect_members(manifest_path)?;
+    let mut packages: Vec<(String, String)> = members
+        .iter()
+        .flat_map(|member| &member.dependencies)
+        .map(|dep| (dep.name.clone(), dep.current_version.to_string()))
+        .collect();
+    packages.sort();
+    packages.dedup();
+
+    let results = crate::logic::check_many(
+        &packages,
+        &crate::Source::CratesIo,
+        crate::BatchLimits::default(),
+    );
+    let checked: BTreeMap<_, _> = packages.into_iter().zip(results).collect();
+
+    let members = members
+        .into_iter()
+        .map(|member| MemberReport {
+            name: member.name,
+            manifest_path: member.manifest_path,
+            dependencies: member
+                .dependencies
+                .into_iter()
+                .map(|dep| {
+                    let key = (dep.name.clone(), dep.current_version.to_string());
+                    let result = match checked.get(&key) {
+                        Some(Ok(info)) => Ok(info.clone()),
+                        Some(Err(e)) => Err(anyhow::anyhow!("{e:#}")),
+                        None => Err(anyhow::anyhow!("Dependency was not checked")),
+                    };
+                    DependencyReport {
+                        name: dep.name,
+                        current_version: dep.current_version,
+                        result,
+                    }
+                })
+                .collect(),
+        })
+        .collect();
+    Ok(WorkspaceReport { members })
+}
+
+/// Reads and parses a `Cargo.toml` manifest.
+fn read_manifest(path: &Path) -> anyhow::Result<Manifest> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read {}: {e}", path.display()))?;
+    toml::from_str(&content).map_err(|e| anyhow::anyhow!("Failed to parse {}: {e}", path.display()))
+}
+
+/// Reads the crates.io packages from a `Cargo.lock`, or none if it does not exist.
+fn read_lockfile(path: &Path) -> anyhow::Result<Vec<LockedPackage>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read {}: {e}", path.display()))?;
+    let lockfile: Lockfile = toml::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("Failed to parse {}: {e}", path.display()))?;
+    Ok(lockfile
+        .package
+        .into_iter()
+        .filter(|package| package.source.as_deref() == Some(CRATES_IO_SOURCE))
+        .collect())
+}
+
+/// Resolves the member directories of a workspace, expanding globs.
+///
+/// Like cargo, `*` and `?` match within a single path component, e.g.
+/// `crates/*` or `crates/foo-*`, and only directories with a `Cargo.toml`
+/// are members.
+fn member_dirs(root_dir: &Path, workspace: &ManifestWorkspace) -> anyhow::Result<Vec<PathBuf>> {
+    let mut dirs = Vec::new();
+    for pattern in &workspace.members {
+        if pattern.contains("**") || pattern.contains(['[', ']', '{', '}']) {
+            anyhow::bail!(
+                "Unsupported workspace member pattern '{pattern}': only `*` and `?` within a path component are supported"
+            );
+        }
+        if !pattern.contains(['*', '?']) {
+            dirs.push(root_dir.join(pattern));
+            continue;
+        }
+        let mut matches = vec![root_dir.to_path_buf()];
+        for component in pattern.split('/').filter(|c| !c.is_empty() && *c != ".") {
+            if component.contains(['*', '?']) {
+                let mut expanded = Vec::new();
+                for dir in &matches {
+                    expanded.extend(matching_dirs(dir, component)?);
+                }
+                matches = expanded;
+            } else {
+                for dir in &mut matches {
+                    dir.push(component);
+                }
+            }
+        }
+        matches.retain(|dir| dir.join("Cargo.toml").is_file());
+        matches.sort();
+        dirs.extend(matches);
+    }
+    let excluded: Vec<PathBuf> = workspace
+        .exclude
+        .iter()
+        .map(|path| root_dir.join(path))
+        .collect();
+    dirs.retain(|dir| dir != root_dir && !excluded.contains(dir));
+    Ok(dirs)
+}
+
+/// Returns the directories in `dir` whose name matches the glob `component`.
+///
+/// A missing `dir` has no matches, as a prefix of the pattern may only exist
+/// for some of the directories expanded before.
+fn matching_dirs(dir: &Path, component: &str) -> anyhow::Result<Vec<PathBuf>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    Ok(fs::read_dir(dir)
+        .map_err(|e| anyhow::anyhow!("Failed to read {}: {e}", dir.display()))?
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_str()
+                .is_some_and(|name| crate::data::glob_match(component, name))
+        })
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect())
+}
+
+/// Builds a member from its manifest, or `None` if it is not a package.
+fn member_from_manifest(
+    dir: &Path,
+    manifest: &Manifest,
+    workspace_deps: &BTreeMap<String, DependencySpec>,
+    locked: &[LockedPackage],
+) -> Option<Member> {
+    let package = manifest.package.as_ref()?;
+    let mut dependencies: Vec<Dependency> = manifest
+        .dependencies
+        .iter()
+        .chain(&manifest.dev_dependencies)
+        .chain(&manifest.build_dependencies)
+        .chain(manifest.target.values().flat_map(|target| {
+            target
+                .dependencies
+                .iter()
+                .chain(&target.dev_dependencies)
+                .chain(&target.build_dependencies)
+        }))
+        .filter_map(|(key, spec)| resolve_dependency(key, spec, workspace_deps, locked))
+        .collect();
+    dependencies.sort_by(|a, b| a.name.cmp(&b.name));
+    dependencies.dedup_by(|a, b| a.name == b.name && a.current_version == b.current_version);
+    Some(Member {
+        name: package.name.clone(),
+        manifest_path: dir.join("Cargo.toml"),
+        dependencies,
+    })
+}
+
+/// Resolves a dependency entry to a crates.io package and its locked version.
+fn resolve_dependency(
+    key: &str,
+    spec: &DependencySpec,
+    workspace_deps: &BTreeMap<String, DependencySpec>,
+    locked: &[LockedPackage],
+) -> Option<Dependency> {
+    let (name, requirement) = match spec {
+        DependencySpec::Simple(version) => (key.to_owned(), Some(version.clone())),
+        DependencySpec::Detailed(detail) if detail.workspace => {
+            // Like cargo, a workspace dependency cannot inherit itself.
+            let inherited = workspace_deps.get(key)?;
+            let mut dependency = resolve_dependency(key, inherited, &BTreeMap::new(), locked)?;
+            if let Some(package) = &detail.package {
+                dependency.name.clone_from(package);
+            }
+            return Some(dependency);
+        }
+        DependencySpec::Detailed(detail) => {
+            if detail.path.is_some() || detail.git.is_some() || detail.registry.is_some() {
+                return None;
+            }
+            (
+                detail.package.clone().unwrap_or_else(|| key.to_owned()),
+                detail.version.clone(),
+            )
+        }
+    };
+    let requirement = requirement
+        .and_then(|req| VersionReq::parse(&req).ok())
+        .unwrap_or(VersionReq::STAR);
+    let current_version = locked
+        .iter()
+        .filter(|package| package.name == name && requirement.matches(&package.version))
+        .map(|package| &package.version)
+        .max()?
+        .clone();
+    Some(Dependency {
+        name,
+        current_version,
+    })
+}
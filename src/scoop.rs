@@ -0,0 +1,45 @@
+use serde::Deserialize;
+
+use crate::data::{ReleaseInfo, parse_loose_version};
+
+/// The fields of a Scoop app manifest used for update checks.
+#[derive(Deserialize)]
+struct ScoopManifest {
+    version: String,
+    homepage: Option<String>,
+}
+
+impl ReleaseInfo {
+    /// Creates a `ReleaseInfo` from a Scoop app manifest.
+    ///
+    /// The manifest's `version` becomes the latest version and its
+    /// `homepage` the URL for more information. The upgrade command is
+    /// `scoop update <name>`.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The manifest, e.g. `bucket/<name>.json` of a bucket
+    /// * `location` - The URL or path of the manifest, used if it has no homepage
+    /// * `name` - The app name
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest cannot be parsed or its version is
+    /// not a version.
+    pub(crate) fn from_scoop_manifest(
+        json: &str,
+        location: &str,
+        name: &str,
+    ) -> anyhow::Result<Self> {
+        let manifest: ScoopManifest = serde_json::from_str(json)
+            .map_err(|e| anyhow::anyhow!("Failed to parse Scoop manifest {location}: {e}"))?;
+        let version = parse_loose_version(&manifest.version).ok_or_else(|| {
+            anyhow::anyhow!("Failed to parse latest version: '{}'", manifest.version)
+        })?;
+        let url = manifest.homepage.unwrap_or_else(|| location.to_owned());
+        Ok(Self {
+            upgrade_command: Some(format!("scoop update {name}")),
+            ..Self::new(version, url)
+        })
+    }
+}
@@ -0,0 +1,632 @@
+#![cfg_attr(
+    feature = "blocking",
+    expect(clippy::redundant_pub_crate, reason = "This is a library module")
+)]
+use core::str::FromStr;
+
+#[cfg(feature = "blocking")]
+use crate::UpdateAvailable;
+use crate::{Source, data::ReleaseInfo, data::UpdateInfo};
+
+/// A place where the latest release of a package can be looked up.
+///
+/// The built-in sources are available through [`Source`]. Implement this
+/// trait to check other places, such as a company-internal artifact store,
+/// and pass the implementation as [`Source::Custom`] or call
+/// [`UpdateSource::check`] directly.
+///
+/// # Examples
+///
+/// ```rust
+/// use semver::Version;
+/// use update_available::{ReleaseInfo, Source, UpdateSource, print_check};
+///
+/// struct ArtifactStore;
+///
+/// impl UpdateSource for ArtifactStore {
+///     fn latest(&self, name: &str) -> anyhow::Result<ReleaseInfo> {
+///         // Query the artifact store here
+///         Ok(ReleaseInfo::new(
+///             Version::new(1, 2, 0),
+///             format!("https://artifacts.example.com/{name}"),
+///         ))
+///     }
+/// }
+///
+/// if let Ok(info) = ArtifactStore.check("my-tool", "1.0.0") {
+///     println!("{info}");
+/// }
+/// print_check("my-tool", "1.0.0", Source::Custom(Box::new(ArtifactStore)));
+/// ```
+pub trait UpdateSource {
+    /// Looks up the latest release of the package.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the package to look up
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the latest release cannot be determined.
+    fn latest(&self, name: &str) -> anyhow::Result<ReleaseInfo>;
+
+    /// Checks whether a newer version than the current one is available.
+    ///
+    /// The default implementation compares the result of
+    /// [`UpdateSource::latest`] with the current version.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the package to check
+    /// * `current_version` - The current version string (e.g., "1.0.0")
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the latest release cannot be determined or the
+    /// current version cannot be parsed.
+    fn check(&self, name: &str, current_version: &str) -> anyhow::Result<UpdateInfo> {
+        UpdateInfo::from_release(name, self.latest(name)?, current_version)
+    }
+}
+
+impl Source {
+    /// Combines sources into a fallback chain.
+    ///
+    /// The sources are tried in order, and the next one is only tried if the
+    /// previous one fails, so a transient outage of one service does not hide
+    /// an update another service could have reported. If every source fails,
+    /// the errors of all sources are reported.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use update_available::{print_check, Source};
+    ///
+    /// let source = Source::chain([Source::Github("serde-rs".to_string()), Source::CratesIo]);
+    /// print_check("serde", "1.0.0", source);
+    /// ```
+    #[must_use]
+    pub fn chain(sources: impl IntoIterator<Item = Self>) -> Self {
+        Self::Chain(sources.into_iter().collect())
+    }
+
+    /// Combines sources into one reporting the highest version of any of them.
+    ///
+    /// All sources are queried concurrently. Failing sources are ignored as
+    /// long as one succeeds; if every source fails, the errors of all sources
+    /// are reported. Use [`crate::check_sources`] for the result of each source.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use update_available::{print_check, Source};
+    ///
+    /// let source = Source::highest([Source::CratesIo, Source::Github("serde-rs".to_string())]);
+    /// print_check("serde", "1.0.0", source);
+    /// ```
+    #[must_use]
+    pub fn highest(sources: impl IntoIterator<Item = Self>) -> Self {
+        Self::Highest(sources.into_iter().collect())
+    }
+
+    /// Sends an extra header with every request to the source.
+    ///
+    /// Use it to pass API gateways requiring e.g. an `X-Api-Key`, or to
+    /// override a header the source sets, such as `Accept`. Headers of the
+    /// same name set by the source are replaced. In a [`Source::chain`] or
+    /// [`Source::highest`], the header is sent to all combined sources.
+    ///
+    /// Only applies to the built-in HTTP sources, not to [`Source::Custom`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use update_available::{print_check, Source};
+    ///
+    /// let source = Source::JsonEndpoint {
+    ///     url: "https://gateway.example.com/releases/{name}".to_string(),
+    ///     version_pointer: "/version".to_string(),
+    ///     url_pointer: None,
+    ///     notes_pointer: None,
+    /// }
+    /// .with_header("X-Api-Key", "secret");
+    /// print_check("my-tool", "1.0.0", source);
+    /// ```
+    #[must_use]
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        let header = (name.to_owned(), value.to_owned());
+        if let Self::Headers { headers, .. } = &mut self {
+            headers.push(header);
+            return self;
+        }
+        Self::Headers {
+            source: Box::new(self),
+            headers: vec![header],
+        }
+    }
+
+    /// Sends a username and password with every request to the source, as
+    /// HTTP basic authentication.
+    ///
+    /// For self-hosted forges, such as older Gitea setups, whose reverse
+    /// proxy only lets basic authentication through. Like other headers, the
+    /// credentials take precedence over a token set in the environment.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use update_available::{print_check, Source};
+    ///
+    /// let source = Source::Gitea("user".to_string(), "https://gitea.example.com".to_string())
+    ///     .with_basic_auth("ci-bot", "s3cret");
+    /// print_check("my-tool", "1.0.0", source);
+    /// ```
+    #[cfg(feature = "blocking")]
+    #[must_use]
+    pub fn with_basic_auth(self, username: &str, password: &str) -> Self {
+        self.with_header(
+            "Authorization",
+            &crate::http::basic_authorization(username, password),
+        )
+    }
+}
+
+impl Source {
+    /// Parses a source, also returning the repository name if it contains one.
+    ///
+    /// The following forms are supported:
+    /// * `crates-io` (or `crates.io`)
+    /// * `packagist`
+    /// * `homebrew` or `homebrew:cask`
+    /// * `aur`
+    /// * `repology`
+    /// * `github:user` or `github:user/repo`
+    /// * `gitea:https://host/user` or `gitea:https://host/user/repo`
+    /// * `gitlab:https://host/group/project`, with any number of nested
+    ///   groups, or `gitlab:https://host/12345` for a project ID
+    /// * Repository URLs such as `https://github.com/user/repo.git`; GitLab
+    ///   hosts (containing `gitlab`) are checked through their git tags with
+    ///   [`Source::Git`], and other hosts are assumed to be Gitea instances
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not one of the supported forms.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::Source;
+    ///
+    /// let (source, repository) = Source::parse_with_repository("github:serde-rs/serde").unwrap();
+    /// assert!(matches!(source, Source::Github(user) if user == "serde-rs"));
+    /// assert_eq!(repository.as_deref(), Some("serde"));
+    /// ```
+    pub fn parse_with_repository(s: &str) -> anyhow::Result<(Self, Option<String>)> {
+        let s = s.trim();
+        if s == "crates-io" || s == "crates.io" {
+            return Ok((Self::CratesIo, None));
+        }
+        match s {
+            "packagist" => return Ok((Self::Packagist, None)),
+            "homebrew" => return Ok((Self::Homebrew { cask: false }, None)),
+            "homebrew:cask" => return Ok((Self::Homebrew { cask: true }, None)),
+            "aur" => return Ok((Self::Aur, None)),
+            "repology" => return Ok((Self::Repology, None)),
+            _ => {}
+        }
+        if let Some(path) = s.strip_prefix("github:") {
+            let (user, repository) = split_path(path, s)?;
+            return Ok((Self::Github(user), repository));
+        }
+        let url = s
+            .strip_prefix("gitea:")
+            .or_else(|| s.strip_prefix("gitlab:"))
+            .unwrap_or(s);
+        let (base_url, path) = url
+            .split_once("://")
+            .and_then(|(scheme, rest)| {
+                let (host, path) = rest.split_once('/')?;
+                Some((format!("{scheme}://{host}"), path))
+            })
+            .ok_or_else(|| anyhow::anyhow!("Unsupported source '{s}'"))?;
+        if s.starts_with("gitlab:") || base_url.contains("gitlab") {
+            return gitlab_source(base_url, path, s.starts_with("gitlab:"), s);
+        }
+        let (user, repository) = split_path(path, s)?;
+        if s.starts_with("gitea:") {
+            Ok((Self::Gitea(user, base_url), repository))
+        } else if base_url == "https://github.com" {
+            Ok((Self::Github(user), repository))
+        } else {
+            Ok((Self::Gitea(user, base_url), repository))
+        }
+    }
+
+    /// Creates the source of a package from the `repository` field of its
+    /// manifest.
+    ///
+    /// Usually called through [`crate::repository_source!`], which passes the
+    /// `CARGO_PKG_REPOSITORY` and `CARGO_PKG_NAME` of the calling crate.
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - The repository URL, e.g. `https://github.com/user/repo`
+    /// * `package` - The package name, used if the URL names no repository
+    ///
+    /// # Returns
+    ///
+    /// Returns the source and the name to check it with, which is the
+    /// repository name if the URL contains one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository is empty or not a supported URL.
+    pub fn from_repository(repository: &str, package: &str) -> anyhow::Result<(Self, String)> {
+        if repository.trim().is_empty() {
+            anyhow::bail!("{package} has no repository in its manifest");
+        }
+        let (source, name) = Self::parse_with_repository(repository)?;
+        Ok((source, name.unwrap_or_else(|| package.to_owned())))
+    }
+}
+
+impl Source {
+    /// Returns a short, stable name of the kind of source, e.g. `crates-io`,
+    /// used to label metrics.
+    ///
+    /// Sources with extra headers return the kind of the wrapped source.
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            Self::CratesIo => "crates-io",
+            Self::Github(_) => "github",
+            Self::Gitea(..) => "gitea",
+            Self::Gitlab { .. } => "gitlab",
+            Self::GitlabPackages { .. } => "gitlab-packages",
+            Self::Homebrew { .. } => "homebrew",
+            Self::Aur => "aur",
+            Self::Debian { .. } => "debian",
+            Self::Repology => "repology",
+            Self::Scoop { .. } => "scoop",
+            Self::Terraform { .. } => "terraform",
+            Self::Tauri { .. } => "tauri",
+            Self::Oci { .. } => "oci",
+            Self::Packagist => "packagist",
+            Self::JsonEndpoint { .. } => "json-endpoint",
+            Self::Git { .. } => "git",
+            #[cfg(feature = "feed")]
+            Self::Feed { .. } => "feed",
+            #[cfg(feature = "scrape")]
+            Self::Scrape { .. } => "scrape",
+            Self::File(_) => "file",
+            Self::Chain(_) => "chain",
+            Self::Highest(_) => "highest",
+            Self::Headers { source, .. } => source.kind(),
+            Self::Custom(_) => "custom",
+        }
+    }
+
+    /// Returns the `host:port` contacted by this source, for connectivity probes.
+    ///
+    /// Chains and aggregations return the address of their first source.
+    /// File and custom sources return `None`, since they contact no known host.
+    pub(crate) fn probe_address(&self) -> Option<String> {
+        let url = match self {
+            Self::CratesIo => return host_address(&crate::config::crates_io_url()),
+            Self::Github(_) => return host_address(&crate::config::github_api_url()),
+            Self::Packagist => "https://repo.packagist.org",
+            Self::Homebrew { .. } => "https://formulae.brew.sh",
+            Self::Aur => "https://aur.archlinux.org",
+            Self::Repology => "https://repology.org",
+            Self::Debian { .. } => "https://qa.debian.org",
+            Self::Oci { registry, .. } => registry,
+            Self::Scoop { manifest } => manifest,
+            Self::Terraform { registry } => registry,
+            Self::Tauri { url } => url,
+            Self::Gitea(_, url)
+            | Self::Gitlab { url, .. }
+            | Self::GitlabPackages { url, .. }
+            | Self::JsonEndpoint { url, .. }
+            | Self::Git { url } => url,
+            #[cfg(feature = "feed")]
+            Self::Feed { url } => url,
+            #[cfg(feature = "scrape")]
+            Self::Scrape { url, .. } => url,
+            Self::Chain(sources) | Self::Highest(sources) => {
+                return sources.first()?.probe_address();
+            }
+            Self::Headers { source, .. } => return source.probe_address(),
+            Self::File(_) | Self::Custom(_) => return None,
+        };
+        host_address(url)
+    }
+}
+
+/// Returns the `host:port` of a URL, defaulting to the port of its scheme.
+fn host_address(url: &str) -> Option<String> {
+    let (scheme, rest) = url.split_once("://")?;
+    let host = rest.split(['/', '?', '#']).next()?;
+    if host.is_empty() {
+        return None;
+    }
+    if host.contains(':') && !host.ends_with(']') {
+        return Some(host.to_owned());
+    }
+    let port = if scheme == "http" { 80 } else { 443 };
+    Some(format!("{host}:{port}"))
+}
+
+/// Creates the source of a GitLab project path, which may contain nested
+/// groups, or project ID.
+///
+/// With `releases`, the project is checked through the releases API as
+/// [`Source::Gitlab`]; otherwise through its git tags as [`Source::Git`].
+fn gitlab_source(
+    base_url: String,
+    path: &str,
+    releases: bool,
+    source: &str,
+) -> anyhow::Result<(Source, Option<String>)> {
+    let path = path.trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    if releases && !path.is_empty() && path.bytes().all(|byte| byte.is_ascii_digit()) {
+        let project = path.to_owned();
+        return Ok((
+            Source::Gitlab {
+                url: base_url,
+                project,
+            },
+            None,
+        ));
+    }
+    let Some((namespace, repository)) = path
+        .rsplit_once('/')
+        .filter(|_| !path.split('/').any(str::is_empty))
+    else {
+        anyhow::bail!("Expected a namespace/project path in source '{source}'");
+    };
+    let source = if releases {
+        Source::Gitlab {
+            url: base_url,
+            project: format!("{namespace}/{{name}}"),
+        }
+    } else {
+        Source::Git {
+            url: format!("{base_url}/{namespace}/{repository}.git"),
+        }
+    };
+    Ok((source, Some(repository.to_owned())))
+}
+
+/// Splits a `user` or `user/repo` path, ignoring a trailing `/` or `.git`.
+fn split_path(path: &str, source: &str) -> anyhow::Result<(String, Option<String>)> {
+    let path = path.trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    let mut parts = path.split('/');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(user), None, None) if !user.is_empty() => Ok((user.to_owned(), None)),
+        (Some(user), Some(repository), None) if !user.is_empty() && !repository.is_empty() => {
+            Ok((user.to_owned(), Some(repository.to_owned())))
+        }
+        _ => Err(anyhow::anyhow!(
+            "Expected a user or user/repo path in source '{source}'"
+        )),
+    }
+}
+
+impl FromStr for Source {
+    type Err = anyhow::Error;
+
+    /// Parses a source from a shorthand string or repository URL.
+    ///
+    /// See [`Source::parse_with_repository`] for the supported forms. The
+    /// repository name is not part of the source; it is the name passed to
+    /// the check functions.
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        Self::parse_with_repository(s).map(|(source, _)| source)
+    }
+}
+
+/// The results of checking a package on several sources.
+///
+/// Created by [`crate::check_sources`].
+pub struct SourcesReport {
+    /// The result of each source, in the order the sources were given.
+    pub results: Vec<anyhow::Result<UpdateInfo>>,
+}
+
+impl SourcesReport {
+    /// Returns the successful result with the highest latest version.
+    ///
+    /// If several sources report the same version, the first one wins.
+    #[must_use]
+    pub fn highest(&self) -> Option<&UpdateInfo> {
+        self.results
+            .iter()
+            .flatten()
+            .rev()
+            .max_by(|a, b| a.latest_version.cmp(&b.latest_version))
+    }
+}
+
+/// Runs `f` for every source concurrently, returning the results in order.
+#[cfg(feature = "blocking")]
+pub(crate) fn concurrently<T: Send>(
+    sources: &[Source],
+    f: impl Fn(&Source) -> anyhow::Result<T> + Sync,
+) -> Vec<anyhow::Result<T>> {
+    let config = crate::http::current();
+    std::thread::scope(|scope| {
+        // All checks must be spawned before joining.
+        let handles: Vec<_> = sources
+            .iter()
+            .map(|source| scope.spawn(|| crate::http::with_config(config.clone(), || f(source))))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|_| Err(anyhow::anyhow!("Update check panicked")))
+            })
+            .collect()
+    })
+}
+
+/// Returns the successful result with the highest version.
+///
+/// # Errors
+///
+/// Returns an error listing the failure of every source if all of them fail.
+#[cfg(feature = "blocking")]
+fn highest<T>(
+    results: Vec<anyhow::Result<T>>,
+    version: impl Fn(&T) -> &semver::Version,
+) -> anyhow::Result<T> {
+    let mut errors = Vec::new();
+    let mut best: Option<T> = None;
+    for result in results {
+        match result {
+            Ok(value) => {
+                if best
+                    .as_ref()
+                    .is_none_or(|best| version(&value) > version(best))
+                {
+                    best = Some(value);
+                }
+            }
+            Err(e) => errors.push(format!("{e:#}")),
+        }
+    }
+    match best {
+        Some(best) => Ok(best),
+        None if errors.is_empty() => anyhow::bail!("The source list is empty"),
+        None => anyhow::bail!("All sources failed: {}", errors.join("; ")),
+    }
+}
+
+/// Returns the result of the first source in the chain that succeeds.
+///
+/// # Errors
+///
+/// Returns an error listing the failure of every source if all of them fail.
+fn first_ok<T>(sources: &[Source], f: impl Fn(&Source) -> anyhow::Result<T>) -> anyhow::Result<T> {
+    let mut errors = Vec::new();
+    for source in sources {
+        match f(source) {
+            Ok(value) => return Ok(value),
+            Err(e) => errors.push(format!("{e:#}")),
+        }
+    }
+    if errors.is_empty() {
+        anyhow::bail!("The source chain is empty");
+    }
+    anyhow::bail!("All sources failed: {}", errors.join("; "))
+}
+
+#[cfg(feature = "blocking")]
+impl UpdateSource for Source {
+    #[expect(
+        clippy::literal_string_with_formatting_args,
+        reason = "`{name}` is a placeholder in the endpoint URL"
+    )]
+    fn latest(&self, name: &str) -> anyhow::Result<ReleaseInfo> {
+        let update_available = UpdateAvailable::new(name, "");
+        match self {
+            Self::CratesIo => update_available.crates_io_latest(),
+            Self::Github(user) => update_available.github_latest(user),
+            Self::Gitea(user, gitea_url) => update_available.gitea_latest(user, gitea_url),
+            Self::Packagist => crate::logic::packagist(name),
+            Self::Homebrew { cask } => crate::logic::homebrew(name, *cask),
+            Self::Aur => crate::logic::aur(name),
+            Self::Repology => crate::logic::repology(name),
+            Self::Debian { suite, ubuntu } => crate::logic::madison(name, suite, *ubuntu),
+            Self::Terraform { registry } => crate::logic::terraform_registry(registry, name),
+            Self::Tauri { url } => crate::logic::tauri(&url.replace("{name}", name)),
+            Self::Scoop { manifest } => {
+                crate::logic::scoop(&manifest.replace("{name}", name), name)
+            }
+            Self::Oci {
+                registry,
+                credentials,
+            } => crate::logic::oci(registry, name, credentials.as_ref()),
+            Self::JsonEndpoint {
+                url,
+                version_pointer,
+                url_pointer,
+                notes_pointer,
+            } => crate::logic::json_endpoint(
+                &url.replace("{name}", name),
+                version_pointer,
+                url_pointer.as_deref(),
+                notes_pointer.as_deref(),
+            ),
+            Self::Git { url } => crate::logic::git_tags(&url.replace("{name}", name)),
+            Self::Gitlab { url, project } => {
+                crate::logic::gitlab(url, &project.replace("{name}", name))
+            }
+            Self::GitlabPackages { url, project } => {
+                crate::logic::gitlab_packages(url, &project.replace("{name}", name), name)
+            }
+            #[cfg(feature = "feed")]
+            Self::Feed { url } => crate::logic::feed(&url.replace("{name}", name)),
+            #[cfg(feature = "scrape")]
+            Self::Scrape { url, pattern } => {
+                crate::logic::scrape(&url.replace("{name}", name), pattern)
+            }
+            Self::File(path) => ReleaseInfo::from_file(std::path::Path::new(
+                &path.to_string_lossy().replace("{name}", name),
+            )),
+            Self::Chain(sources) => first_ok(sources, |source| source.latest(name)),
+            Self::Highest(sources) => highest(
+                concurrently(sources, |source| source.latest(name)),
+                |release| &release.version,
+            ),
+            Self::Headers { source, headers } => {
+                crate::http::with_headers(headers, || source.latest(name))
+            }
+            Self::Custom(source) => source.latest(name),
+        }
+    }
+
+    /// Checks the source, aggregating the release notes of all skipped
+    /// releases for GitHub and Gitea.
+    fn check(&self, name: &str, current_version: &str) -> anyhow::Result<UpdateInfo> {
+        let update_available = UpdateAvailable::new(name, current_version);
+        match self {
+            Self::CratesIo => update_available.crates_io(),
+            Self::Github(user) => update_available.github(user),
+            Self::Gitea(user, gitea_url) => update_available.gitea(user, gitea_url),
+            Self::JsonEndpoint { .. }
+            | Self::Packagist
+            | Self::Homebrew { .. }
+            | Self::Aur
+            | Self::Repology
+            | Self::Debian { .. }
+            | Self::Terraform { .. }
+            | Self::Tauri { .. }
+            | Self::Scoop { .. }
+            | Self::Oci { .. }
+            | Self::Git { .. }
+            | Self::Gitlab { .. }
+            | Self::GitlabPackages { .. }
+            | Self::File(_) => UpdateInfo::from_release(name, self.latest(name)?, current_version),
+            #[cfg(feature = "feed")]
+            Self::Feed { .. } => {
+                UpdateInfo::from_release(name, self.latest(name)?, current_version)
+            }
+            #[cfg(feature = "scrape")]
+            Self::Scrape { .. } => {
+                UpdateInfo::from_release(name, self.latest(name)?, current_version)
+            }
+            Self::Chain(sources) => first_ok(sources, |source| source.check(name, current_version)),
+            Self::Highest(sources) => highest(
+                concurrently(sources, |source| source.check(name, current_version)),
+                |info| &info.latest_version,
+            ),
+            Self::Headers { source, headers } => {
+                crate::http::with_headers(headers, || source.check(name, current_version))
+            }
+            Self::Custom(source) => source.check(name, current_version),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use unicode_width::UnicodeWidthStr as _;
+
+use crate::data::UpdateKind;
+use crate::report::UpdateReport;
+
+/// The column headers of the table.
+const HEADERS: [&str; 5] = ["Package", "Current", "Latest", "Kind", "URL"];
+
+impl UpdateReport {
+    /// Renders the outdated packages as a table with aligned columns.
+    ///
+    /// Every outdated package is listed with its current and latest version,
+    /// the kind of update and the URL for more information, like
+    /// `cargo outdated` does. Failed packages are not listed; use
+    /// [`UpdateReport::failed`] for them. Returns an empty string if no
+    /// update is available.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use update_available::{Source, check_many};
+    ///
+    /// let report = check_many(&[("serde", "1.0.0"), ("anyhow", "1.0.0")], &Source::CratesIo);
+    /// print!("{}", report.to_table());
+    /// ```
+    #[must_use]
+    pub fn to_table(&self) -> String {
+        let rows: Vec<[String; 5]> = self
+            .packages
+            .iter()
+            .filter_map(|package| {
+                let info = package.result.as_ref().ok()?;
+                info.is_update_available.then(|| {
+                    [
+                        package.name.clone(),
+                        package.current_version.clone(),
+                        info.latest_version.to_string(),
+                        info.update_kind.map_or("", kind_label).to_owned(),
+                        info.url.clone(),
+                    ]
+                })
+            })
+            .collect();
+        if rows.is_empty() {
+            return String::new();
+        }
+        let mut widths = HEADERS.map(str::width);
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.width());
+            }
+        }
+        let separator = widths.map(|width| "-".repeat(width));
+        let mut table = String::new();
+        push_row(&mut table, &HEADERS, &widths);
+        push_row(&mut table, &separator, &widths);
+        for row in &rows {
+            push_row(&mut table, row, &widths);
+        }
+        table
+    }
+}
+
+/// Appends a row, padding every cell but the last to the column width.
+fn push_row(table: &mut String, cells: &[impl AsRef<str>], widths: &[usize]) {
+    let last = cells.len().saturating_sub(1);
+    for (index, (cell, width)) in cells.iter().zip(widths).enumerate() {
+        let cell = cell.as_ref();
+        table.push_str(cell);
+        if index < last {
+            table.push_str(&" ".repeat(width - cell.width() + 2));
+        }
+    }
+    table.push('\n');
+}
+
+/// Returns the label of an update kind in the table.
+const fn kind_label(kind: UpdateKind) -> &'static str {
+    match kind {
+        UpdateKind::Prerelease => "prerelease",
+        UpdateKind::Patch => "patch",
+        UpdateKind::Minor => "minor",
+        UpdateKind::Major => "major",
+    }
+}
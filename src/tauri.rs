@@ -0,0 +1,241 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::data::{ReleaseInfo, parse_tag_version};
+
+/// A GitHub release with its assets.
+#[cfg(feature = "blocking")]
+#[derive(Deserialize)]
+struct GithubRelease {
+    tag_name: String,
+    body: Option<String>,
+    published_at: Option<String>,
+    #[serde(default)]
+    assets: Vec<GithubAsset>,
+}
+
+/// An asset of a GitHub release.
+#[cfg(feature = "blocking")]
+#[derive(Deserialize)]
+struct GithubAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+/// A Tauri updater manifest, as served as `latest.json` to the Tauri updater.
+///
+/// Read with [`crate::Source::Tauri`], or generated from a GitHub release
+/// with [`TauriManifest::from_github_release`] to publish update metadata.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TauriManifest {
+    /// The version of the release, e.g. `1.2.0`.
+    pub version: String,
+    /// The release notes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    /// The RFC 3339 publication date of the release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pub_date: Option<String>,
+    /// The update bundle of each platform, keyed by `<os>-<arch>`, e.g.
+    /// `darwin-aarch64`, optionally suffixed with the bundle type, e.g.
+    /// `windows-x86_64-msi`.
+    #[serde(default)]
+    pub platforms: BTreeMap<String, TauriPlatform>,
+}
+
+/// The update bundle of a platform in a [`TauriManifest`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TauriPlatform {
+    /// The content of the bundle's `.sig` file.
+    pub signature: String,
+    /// The download URL of the bundle.
+    pub url: String,
+}
+
+impl TauriManifest {
+    /// Serializes the manifest as pretty-printed JSON, ready to be uploaded
+    /// as `latest.json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json_string(&self) -> anyhow::Result<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| anyhow::anyhow!("Failed to serialize Tauri manifest: {e}"))
+    }
+
+    /// Generates a manifest from the latest release of a GitHub repository.
+    ///
+    /// The update bundles built by `tauri build` (`.app.tar.gz`,
+    /// `.AppImage`, `-setup.exe` and `.msi`, or their zipped v1
+    /// variants) are picked from the release assets, together with the
+    /// content of their `.sig` files. Upload the result as `latest.json`
+    /// and point the Tauri updater, or [`crate::Source::Tauri`], at it.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The GitHub username or organization that owns the repository
+    /// * `repository` - The repository name
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the release or a signature cannot be fetched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use update_available::TauriManifest;
+    ///
+    /// let manifest = TauriManifest::from_github_release("user", "my-app").unwrap();
+    /// std::fs::write("latest.json", manifest.to_json_string().unwrap()).unwrap();
+    /// ```
+    #[cfg(feature = "blocking")]
+    pub fn from_github_release(user: &str, repository: &str) -> anyhow::Result<Self> {
+        let (url, _) = crate::UpdateAvailable::new(repository, "").github_urls(user);
+        let mut response = crate::http::get(&url).call()?;
+        if !response.status().is_success() {
+            anyhow::bail!("Failed to fetch data from GitHub: {}", response.status());
+        }
+        let release: GithubRelease = response.body_mut().read_json()?;
+        let assets: Vec<(String, String)> = release
+            .assets
+            .into_iter()
+            .map(|asset| (asset.name, asset.browser_download_url))
+            .collect();
+        Self::from_assets(
+            &release.tag_name,
+            release.body,
+            release.published_at,
+            &assets,
+            |url| Ok(crate::http::get(url).call()?.body_mut().read_to_string()?),
+        )
+    }
+
+    /// Builds a manifest from the assets of a release.
+    ///
+    /// Every asset recognized as a Tauri update bundle with a matching
+    /// `.sig` asset is added under `<os>-<arch>-<bundle>`, and under
+    /// `<os>-<arch>` for the first bundle of the platform (NSIS installers
+    /// take precedence over MSI ones, as in Tauri's own tooling).
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The version of the release, a leading `v` is ignored
+    /// * `notes` - The release notes
+    /// * `pub_date` - The publication date of the release
+    /// * `assets` - The `(name, download URL)` pairs of the release assets
+    /// * `signature` - Returns the content of a `.sig` asset given its URL
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a signature cannot be fetched.
+    pub(crate) fn from_assets(
+        version: &str,
+        notes: Option<String>,
+        pub_date: Option<String>,
+        assets: &[(String, String)],
+        mut signature: impl FnMut(&str) -> anyhow::Result<String>,
+    ) -> anyhow::Result<Self> {
+        let mut platforms = BTreeMap::new();
+        for (name, url) in assets {
+            let Some((os, arch, bundle)) = bundle_platform(name) else {
+                continue;
+            };
+            let sig_name = format!("{name}.sig");
+            let Some((_, sig_url)) = assets.iter().find(|(name, _)| *name == sig_name) else {
+                continue;
+            };
+            let platform = TauriPlatform {
+                signature: signature(sig_url)?.trim().to_owned(),
+                url: url.clone(),
+            };
+            for arch in arch {
+                let key = format!("{os}-{arch}");
+                if bundle == "nsis" || !platforms.contains_key(&key) {
+                    platforms.insert(key.clone(), platform.clone());
+                }
+                platforms.insert(format!("{key}-{bundle}"), platform.clone());
+            }
+        }
+        Ok(Self {
+            version: version.strip_prefix('v').unwrap_or(version).to_owned(),
+            notes,
+            pub_date,
+            platforms,
+        })
+    }
+}
+
+/// Recognizes a Tauri update bundle by its file name.
+///
+/// Returns the operating system, the architectures and the bundle type, e.g.
+/// `("darwin", ["aarch64"], "app")` for `App_aarch64.app.tar.gz`. Universal
+/// macOS bundles serve both architectures.
+#[expect(
+    clippy::case_sensitive_file_extension_comparisons,
+    reason = "The name is lowercased"
+)]
+pub(crate) fn bundle_platform(
+    name: &str,
+) -> Option<(&'static str, Vec<&'static str>, &'static str)> {
+    let lower = name.to_lowercase();
+    let (os, bundle) = if lower.ends_with(".app.tar.gz") {
+        ("darwin", "app")
+    } else if lower.ends_with(".appimage") || lower.ends_with(".appimage.tar.gz") {
+        ("linux", "appimage")
+    } else if lower.ends_with("-setup.exe") || lower.ends_with(".nsis.zip") {
+        ("windows", "nsis")
+    } else if lower.ends_with(".msi") || lower.ends_with(".msi.zip") {
+        ("windows", "msi")
+    } else {
+        return None;
+    };
+    let tokens: Vec<&str> = lower.split(['_', '-', '.']).collect();
+    let arch = if tokens.contains(&"universal") {
+        vec!["x86_64", "aarch64"]
+    } else if tokens
+        .iter()
+        .any(|token| ["aarch64", "arm64"].contains(token))
+    {
+        vec!["aarch64"]
+    } else if lower.contains("x86_64")
+        || tokens.iter().any(|token| ["x64", "amd64"].contains(token))
+    {
+        vec!["x86_64"]
+    } else if tokens
+        .iter()
+        .any(|token| ["x86", "i686", "i386"].contains(token))
+    {
+        vec!["i686"]
+    } else {
+        vec!["x86_64"]
+    };
+    Some((os, arch, bundle))
+}
+
+impl ReleaseInfo {
+    /// Creates a `ReleaseInfo` from a Tauri updater manifest.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The manifest
+    /// * `url` - The URL of the manifest, used as the URL for more information
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest cannot be parsed or its version is
+    /// not a version.
+    pub(crate) fn from_tauri_manifest(json: &str, url: &str) -> anyhow::Result<Self> {
+        let manifest: TauriManifest = serde_json::from_str(json)
+            .map_err(|e| anyhow::anyhow!("Failed to parse Tauri manifest: {e}"))?;
+        let version = manifest.version.as_str();
+        let version = parse_tag_version(version)
+            .ok_or_else(|| anyhow::anyhow!("Failed to parse latest version: '{version}'"))?;
+        Ok(Self {
+            changelog: manifest.notes,
+            ..Self::new(version, url.to_owned())
+        })
+    }
+}
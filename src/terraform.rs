@@ -0,0 +1,118 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+use semver::Version;
+use serde::Deserialize;
+
+use crate::data::{ReleaseInfo, parse_tag_version};
+
+/// A version in a Terraform registry response.
+#[derive(Deserialize)]
+struct RegistryVersion {
+    version: String,
+}
+
+/// Response structure for the provider `versions` API.
+#[derive(Deserialize)]
+struct ProviderVersions {
+    versions: Vec<RegistryVersion>,
+}
+
+/// Response structure for the module `versions` API.
+#[derive(Deserialize)]
+struct ModuleVersions {
+    modules: Vec<ProviderVersions>,
+}
+
+/// Returns whether `name` is a module address (`namespace/name/system`)
+/// rather than a provider address (`namespace/type`).
+///
+/// # Errors
+///
+/// Returns an error if the name has neither form.
+pub(crate) fn is_module(name: &str) -> anyhow::Result<bool> {
+    match name.split('/').filter(|part| !part.is_empty()).count() {
+        2 => Ok(false),
+        3 => Ok(true),
+        _ => Err(anyhow::anyhow!(
+            "Expected a provider (NAMESPACE/TYPE) or module (NAMESPACE/NAME/SYSTEM), got '{name}'"
+        )),
+    }
+}
+
+/// Returns the `versions` API URL of a provider or module.
+///
+/// # Errors
+///
+/// Returns an error if the name is neither a provider nor a module address.
+pub(crate) fn versions_url(registry: &str, name: &str) -> anyhow::Result<String> {
+    let kind = if is_module(name)? {
+        "modules"
+    } else {
+        "providers"
+    };
+    Ok(format!(
+        "{}/v1/{kind}/{name}/versions",
+        registry.trim_end_matches('/')
+    ))
+}
+
+impl ReleaseInfo {
+    /// Creates a `ReleaseInfo` from a Terraform registry `versions` response.
+    ///
+    /// Providers (`namespace/type`) and modules (`namespace/name/system`)
+    /// are told apart by their address. The highest version becomes the
+    /// latest version, and the upgrade command is `terraform init -upgrade`
+    /// (`tofu init -upgrade` for the `OpenTofu` registry).
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The response of the `versions` API
+    /// * `registry` - The registry URL, e.g. `https://registry.terraform.io`
+    /// * `name` - The provider or module address
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the response cannot be parsed or lists no version.
+    pub(crate) fn from_terraform_registry(
+        json: &str,
+        registry: &str,
+        name: &str,
+    ) -> anyhow::Result<Self> {
+        let module = is_module(name)?;
+        let parse_error =
+            |e: serde_json::Error| anyhow::anyhow!("Failed to parse registry response: {e}");
+        let versions = if module {
+            serde_json::from_str::<ModuleVersions>(json)
+                .map_err(parse_error)?
+                .modules
+                .into_iter()
+                .flat_map(|module| module.versions)
+                .collect()
+        } else {
+            serde_json::from_str::<ProviderVersions>(json)
+                .map_err(parse_error)?
+                .versions
+        };
+        let mut versions: Vec<Version> = versions
+            .iter()
+            .filter_map(|release| parse_tag_version(&release.version))
+            .collect();
+        versions.sort();
+        versions.dedup();
+        let registry = registry.trim_end_matches('/');
+        let version = versions
+            .last()
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("No version of {name} found on {registry}"))?;
+        let kind = if module { "modules" } else { "providers" };
+        let tool = if registry.contains("opentofu") {
+            "tofu"
+        } else {
+            "terraform"
+        };
+        Ok(Self {
+            versions,
+            upgrade_command: Some(format!("{tool} init -upgrade")),
+            ..Self::new(version, format!("{registry}/{kind}/{name}/latest"))
+        })
+    }
+}
@@ -0,0 +1,98 @@
+//! Utilities for testing code that checks for updates.
+//!
+//! [`StaticSource`] answers every lookup with a canned release instead of
+//! contacting a server, so applications can unit-test their "update
+//! available" paths without network access.
+//!
+//! This module is only available with the `testing` feature enabled.
+use semver::Version;
+
+use crate::{
+    UpdateSource,
+    data::{CratesResponse, GiteaHubResponse, ReleaseInfo},
+};
+
+/// An [`UpdateSource`] that always reports the same release or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use update_available::UpdateSource;
+/// use update_available::testing::StaticSource;
+///
+/// let source = StaticSource::from_release_json(
+///     r#"{"tag_name": "v1.2.0", "body": "- Faster builds", "html_url": "https://example.com"}"#,
+/// )
+/// .unwrap();
+/// let info = source.check("my-tool", "1.0.0").unwrap();
+/// assert!(info.is_update_available);
+/// ```
+#[derive(Clone, Debug)]
+pub struct StaticSource {
+    result: Result<ReleaseInfo, String>,
+}
+
+impl StaticSource {
+    /// Creates a source reporting the given release.
+    #[must_use]
+    pub const fn new(release: ReleaseInfo) -> Self {
+        Self {
+            result: Ok(release),
+        }
+    }
+
+    /// Creates a source reporting the given version, without a changelog.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the version cannot be parsed.
+    pub fn version(version: &str) -> anyhow::Result<Self> {
+        let version =
+            Version::parse(version).map_err(|e| anyhow::anyhow!("Failed to parse version: {e}"))?;
+        Ok(Self::new(ReleaseInfo::new(
+            version,
+            "https://example.com".to_owned(),
+        )))
+    }
+
+    /// Creates a source from a canned crates.io API response, as returned by
+    /// `https://crates.io/api/v1/crates/{name}`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is not a crates.io response.
+    pub fn from_crates_io_json(json: &str) -> anyhow::Result<Self> {
+        let response: CratesResponse = serde_json::from_str(json)
+            .map_err(|e| anyhow::anyhow!("Failed to parse crates.io response: {e}"))?;
+        Ok(Self::new(ReleaseInfo::from_crates(response)))
+    }
+
+    /// Creates a source from a canned GitHub or Gitea release, as returned by
+    /// the `releases/latest` API endpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is not a release or its tag is not a version.
+    pub fn from_release_json(json: &str) -> anyhow::Result<Self> {
+        let response: GiteaHubResponse = serde_json::from_str(json)
+            .map_err(|e| anyhow::anyhow!("Failed to parse release: {e}"))?;
+        Ok(Self::new(ReleaseInfo::from_gitea_or_hub(response, &[])?))
+    }
+
+    /// Creates a source whose lookups fail with the given message, e.g. to
+    /// test how a network failure is handled.
+    #[must_use]
+    pub fn failing(message: &str) -> Self {
+        Self {
+            result: Err(message.to_owned()),
+        }
+    }
+}
+
+impl UpdateSource for StaticSource {
+    fn latest(&self, _name: &str) -> anyhow::Result<ReleaseInfo> {
+        self.result
+            .clone()
+            .map_err(|message| anyhow::anyhow!(message))
+    }
+}
@@ -0,0 +1,104 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+use crate::Source;
+
+/// Stores the tokens of sources in the platform keychain.
+///
+/// Uses the macOS Keychain, the Windows Credential Manager or the Secret
+/// Service on Linux, so applications, especially GUI ones, do not have to
+/// keep tokens in plaintext configuration files. Tokens are stored under the
+/// service name of the application, with the host the source contacts as the
+/// account, e.g. `api.github.com` for [`Source::Github`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use update_available::{Checker, Source, TokenStore};
+///
+/// let store = TokenStore::new("my-tool");
+/// let source = Source::Github("user".into());
+/// // E.g. from a settings dialog:
+/// store.store(&source, "ghp_...")?;
+///
+/// let checker = Checker::new("my-tool", "1.0.0", source).keyring_token(&store);
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenStore {
+    service: String,
+}
+
+impl TokenStore {
+    /// Creates a store for the tokens of an application.
+    ///
+    /// # Arguments
+    ///
+    /// * `service` - The service name the tokens are stored under, usually
+    ///   the name of the application
+    #[must_use]
+    pub fn new(service: &str) -> Self {
+        Self {
+            service: service.to_owned(),
+        }
+    }
+
+    /// Returns the stored token of a source, or `None` if there is none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source contacts no known host or the keychain
+    /// cannot be accessed.
+    pub fn load(&self, source: &Source) -> anyhow::Result<Option<String>> {
+        match self.entry(source)?.get_password() {
+            Ok(token) => Ok(Some(token)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(e) => Err(anyhow::anyhow!(
+                "Failed to read the token from the keychain: {e}"
+            )),
+        }
+    }
+
+    /// Stores the token of a source, replacing a previous one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source contacts no known host or the keychain
+    /// cannot be accessed.
+    pub fn store(&self, source: &Source, token: &str) -> anyhow::Result<()> {
+        self.entry(source)?
+            .set_password(token)
+            .map_err(|e| anyhow::anyhow!("Failed to store the token in the keychain: {e}"))
+    }
+
+    /// Deletes the stored token of a source, if there is one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source contacts no known host or the keychain
+    /// cannot be accessed.
+    pub fn delete(&self, source: &Source) -> anyhow::Result<()> {
+        match self.entry(source)?.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(e) => Err(anyhow::anyhow!(
+                "Failed to delete the token from the keychain: {e}"
+            )),
+        }
+    }
+
+    /// Returns the keychain entry of a source.
+    fn entry(&self, source: &Source) -> anyhow::Result<keyring::Entry> {
+        let account =
+            account(source).ok_or_else(|| anyhow::anyhow!("The source contacts no known host"))?;
+        keyring::Entry::new(&self.service, &account)
+            .map_err(|e| anyhow::anyhow!("Failed to open the keychain: {e}"))
+    }
+}
+
+/// Returns the account a source's token is stored under: the host it
+/// contacts, without the port.
+pub(crate) fn account(source: &Source) -> Option<String> {
+    let address = source.probe_address()?;
+    let host = address
+        .rsplit_once(':')
+        .map_or(address.as_str(), |(host, _)| host);
+    Some(host.to_ascii_lowercase())
+}
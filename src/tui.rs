@@ -0,0 +1,161 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Clear, Paragraph, Widget, Wrap},
+};
+use unicode_width::UnicodeWidthStr as _;
+
+use crate::data::UpdateInfo;
+
+/// The title shown in the top border of the banner.
+const TITLE: &str = " A new version is available! ";
+
+/// A ratatui widget rendering an [`UpdateInfo`] as a bordered banner.
+///
+/// Nothing is rendered if no update is available. The banner clears the
+/// area it is rendered to, so it can be drawn on top of other widgets as a
+/// popup, e.g. in the area returned by [`UpdateBanner::popup_area`]. To make
+/// it dismissible, keep a flag in the application state, stop rendering the
+/// banner when the user presses a key, and tell them which one with
+/// [`UpdateBanner::dismiss_hint`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+/// use update_available::{UpdateBanner, check_crates_io};
+///
+/// if let Ok(info) = check_crates_io("serde", "1.0.0") {
+///     let screen = Rect::new(0, 0, 80, 24);
+///     let mut buffer = Buffer::empty(screen);
+///     let banner = UpdateBanner::new(&info).dismiss_hint("Press Esc to dismiss");
+///     let area = banner.popup_area(screen);
+///     banner.render(area, &mut buffer);
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct UpdateBanner<'a> {
+    info: &'a UpdateInfo,
+    dismiss_hint: Option<&'a str>,
+    changelog_lines: usize,
+}
+
+impl<'a> UpdateBanner<'a> {
+    /// Creates a banner for the given update information.
+    #[must_use]
+    pub const fn new(info: &'a UpdateInfo) -> Self {
+        Self {
+            info,
+            dismiss_hint: None,
+            changelog_lines: 4,
+        }
+    }
+
+    /// Shows a hint on how to dismiss the banner in its bottom border.
+    #[must_use]
+    pub const fn dismiss_hint(mut self, hint: &'a str) -> Self {
+        self.dismiss_hint = Some(hint);
+        self
+    }
+
+    /// Sets the maximum number of changelog lines to show.
+    ///
+    /// Defaults to 4; `0` hides the changelog.
+    #[must_use]
+    pub const fn changelog_lines(mut self, lines: usize) -> Self {
+        self.changelog_lines = lines;
+        self
+    }
+
+    /// Returns an area centered in `area` that fits the banner.
+    #[must_use]
+    pub fn popup_area(&self, area: Rect) -> Rect {
+        let lines = self.lines();
+        let content_width = lines
+            .iter()
+            .map(Line::width)
+            .chain([
+                TITLE.width(),
+                self.dismiss_hint.map_or(0, |hint| hint.width() + 2),
+            ])
+            .max()
+            .unwrap_or(0);
+        let width = u16::try_from(content_width + 4)
+            .unwrap_or(u16::MAX)
+            .min(area.width);
+        let height = u16::try_from(lines.len() + 2)
+            .unwrap_or(u16::MAX)
+            .min(area.height);
+        Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        )
+    }
+
+    /// Returns the lines shown inside the border.
+    fn lines(&self) -> Vec<Line<'a>> {
+        let info = self.info;
+        let mut lines = vec![Line::from(vec![
+            Span::raw(format!("Latest version: {} → ", info.current_version)),
+            Span::styled(
+                info.latest_version.to_string(),
+                Style::new().add_modifier(Modifier::BOLD),
+            ),
+        ])];
+        if let Some(changelog) = &info.changelog {
+            lines.extend(
+                changelog
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                    .take(self.changelog_lines)
+                    .map(|line| {
+                        Line::from(format!(
+                            "  • {}",
+                            line.trim_start_matches(['-', '*', '•', ' '])
+                        ))
+                    }),
+            );
+        }
+        if let Some(command) = &info.upgrade_command {
+            lines.push(Line::from(format!("Update with: {command}")));
+        }
+        lines.push(Line::from(vec![
+            Span::raw("More info: "),
+            Span::styled(
+                info.url.as_str(),
+                Style::new().add_modifier(Modifier::UNDERLINED),
+            ),
+        ]));
+        lines
+    }
+}
+
+impl Widget for UpdateBanner<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
+impl Widget for &UpdateBanner<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if !self.info.is_update_available {
+            return;
+        }
+        let mut block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .title(TITLE);
+        if let Some(hint) = self.dismiss_hint {
+            block = block.title_bottom(Line::from(format!(" {hint} ")).right_aligned());
+        }
+        Clear.render(area, buf);
+        Paragraph::new(self.lines())
+            .block(block)
+            .wrap(Wrap { trim: false })
+            .render(area, buf);
+    }
+}
@@ -0,0 +1,189 @@
+//! Asynchronous update checks for WebAssembly targets.
+//!
+//! The blocking functions at the crate root use `ureq`, which cannot open
+//! connections on `wasm32-unknown-unknown`. The functions in this module use
+//! the JavaScript `fetch` API instead, so they work in browsers, web workers
+//! and JavaScript runtimes such as Node.js or Deno.
+//!
+//! This module is only available with the `wasm` feature enabled. Build with
+//! `default-features = false` to leave out the blocking client.
+#![expect(
+    clippy::future_not_send,
+    reason = "JavaScript promises are bound to the thread that created them"
+)]
+use js_sys::Promise;
+use serde::de::DeserializeOwned;
+use wasm_bindgen::{JsCast, JsValue, prelude::wasm_bindgen};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Request, RequestInit, Response};
+
+use crate::{
+    UpdateAvailable,
+    data::{CratesResponse, GiteaHubResponse, UpdateInfo},
+};
+
+#[wasm_bindgen]
+extern "C" {
+    /// The global `fetch` function, which exists in windows and workers alike.
+    #[wasm_bindgen(js_name = fetch)]
+    fn fetch_with_request(request: &Request) -> Promise;
+}
+
+/// Checks for updates on crates.io for the specified package.
+///
+/// This is the asynchronous counterpart of [`crate::check_crates_io`].
+///
+/// # Arguments
+///
+/// * `name` - The name of the crate to check on crates.io
+/// * `current_version` - The current version string (e.g., "1.0.0")
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The network request fails
+/// * The crates.io API returns an error
+/// * The version strings cannot be parsed
+/// * The response format is unexpected
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # async fn run() {
+/// match update_available::wasm::check_crates_io("serde", "1.0.0").await {
+///     Ok(info) => println!("{info}"),
+///     Err(e) => eprintln!("Error checking for updates: {e}"),
+/// }
+/// # }
+/// ```
+pub async fn check_crates_io(name: &str, current_version: &str) -> anyhow::Result<UpdateInfo> {
+    let update_available = UpdateAvailable::new(name, current_version);
+    let response: CratesResponse =
+        fetch_json(&update_available.crates_io_url(), "crates.io").await?;
+    UpdateInfo::from_crates(response, current_version)
+}
+
+/// Checks for updates on GitHub for the specified repository.
+///
+/// This is the asynchronous counterpart of [`crate::check_github`]. If
+/// several releases were skipped, their notes are aggregated into the
+/// changelog, newest first.
+///
+/// # Arguments
+///
+/// * `name` - The name of the repository to check
+/// * `user` - The GitHub username or organization that owns the repository
+/// * `current_version` - The current version string (e.g., "1.0.0")
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The network request fails
+/// * The GitHub API returns an error
+/// * The version strings cannot be parsed
+/// * The response format is unexpected
+/// * The repository does not exist or has no releases
+pub async fn check_github(
+    name: &str,
+    user: &str,
+    current_version: &str,
+) -> anyhow::Result<UpdateInfo> {
+    let update_available = UpdateAvailable::new(name, current_version);
+    let (latest, releases) = update_available.github_urls(user);
+    let mut info = check_releases(&update_available, &latest, &releases, "GitHub").await?;
+    info.upgrade_command = Some(update_available.github_upgrade_command(user));
+    Ok(info)
+}
+
+/// Checks for updates on Gitea for the specified repository.
+///
+/// This is the asynchronous counterpart of [`crate::check_gitea`]. If
+/// several releases were skipped, their notes are aggregated into the
+/// changelog, newest first.
+///
+/// # Arguments
+///
+/// * `name` - The name of the repository to check
+/// * `user` - The Gitea username or organization that owns the repository
+/// * `gitea_url` - The base URL of the Gitea instance (e.g., <https://gitea.example.com>)
+/// * `current_version` - The current version string (e.g., "1.0.0")
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The network request fails
+/// * The Gitea API returns an error
+/// * The version strings cannot be parsed
+/// * The response format is unexpected
+/// * The repository does not exist or has no releases
+/// * The Gitea URL is invalid or uses plain HTTP
+pub async fn check_gitea(
+    name: &str,
+    user: &str,
+    gitea_url: &str,
+    current_version: &str,
+) -> anyhow::Result<UpdateInfo> {
+    let update_available = UpdateAvailable::new(name, current_version);
+    let gitea_url = crate::config::forge_url(gitea_url)?;
+    let (latest, releases) = update_available.gitea_urls(user, &gitea_url);
+    check_releases(&update_available, &latest, &releases, "Gitea").await
+}
+
+/// Checks the latest release of a GitHub or Gitea repository, aggregating
+/// the notes of skipped releases on a best-effort basis.
+async fn check_releases(
+    update_available: &UpdateAvailable,
+    latest: &str,
+    releases: &str,
+    forge: &str,
+) -> anyhow::Result<UpdateInfo> {
+    let json: GiteaHubResponse = fetch_json(latest, forge).await?;
+    let mut info = UpdateInfo::from_gitea_or_hub(
+        &update_available.name,
+        json,
+        &update_available.current_version,
+    )?;
+    if info.is_update_available
+        && let Ok(releases) = fetch_json::<Vec<GiteaHubResponse>>(releases, forge).await
+    {
+        info.apply_releases(&releases);
+    }
+    Ok(info)
+}
+
+/// Fetches `url` and parses the response body as JSON.
+///
+/// `service` names the service in error messages. Browsers do not allow
+/// setting the `User-Agent` header, so the runtime's default is sent.
+async fn fetch_json<T: DeserializeOwned>(url: &str, service: &str) -> anyhow::Result<T> {
+    if crate::is_disabled() {
+        anyhow::bail!("Update checks are disabled");
+    }
+    let init = RequestInit::new();
+    init.set_method("GET");
+    let request = Request::new_with_str_and_init(url, &init).map_err(|e| js_error(url, &e))?;
+    request
+        .headers()
+        .set("Accept", "application/json")
+        .map_err(|e| js_error(url, &e))?;
+
+    let response: Response = JsFuture::from(fetch_with_request(&request))
+        .await
+        .and_then(JsCast::dyn_into)
+        .map_err(|e| js_error(url, &e))?;
+    if !response.ok() {
+        anyhow::bail!("Failed to fetch data from {service}: {}", response.status());
+    }
+
+    let text = JsFuture::from(response.text().map_err(|e| js_error(url, &e))?)
+        .await
+        .map_err(|e| js_error(url, &e))?
+        .as_string()
+        .ok_or_else(|| anyhow::anyhow!("Failed to read the response from {url}"))?;
+    Ok(serde_json::from_str(&text)?)
+}
+
+/// Converts a JavaScript exception into an error.
+fn js_error(url: &str, error: &JsValue) -> anyhow::Error {
+    anyhow::anyhow!("Failed to fetch {url}: {error:?}")
+}
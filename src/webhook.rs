@@ -0,0 +1,137 @@
+#![expect(clippy::redundant_pub_crate, reason = "This is a library module")]
+use core::fmt::Write as _;
+
+use hmac::{Hmac, KeyInit as _, Mac as _};
+use sha2::Sha256;
+
+use crate::data::UpdateInfo;
+
+/// The header carrying the HMAC-SHA256 signature of a signed request.
+pub const SIGNATURE_HEADER: &str = "X-Update-Available-Signature";
+
+/// The payload format of a [`Webhook`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WebhookFormat {
+    /// The same JSON as [`UpdateInfo::to_json_string`] and the CLI's `--format json`.
+    #[default]
+    Json,
+    /// A Slack message, see [`UpdateInfo::to_slack_message`].
+    Slack,
+    /// A Discord message, see [`UpdateInfo::to_discord_message`].
+    Discord,
+}
+
+/// Sends update information to a webhook as JSON.
+///
+/// By default, the body is the same JSON as [`UpdateInfo::to_json_string`];
+/// use [`Webhook::format`] to post Slack or Discord messages instead. If a
+/// secret is set, the body is signed with HMAC-SHA256 and the signature is
+/// sent in the [`SIGNATURE_HEADER`] header as `sha256=<hex digest>`, like
+/// GitHub's webhook signatures, so the receiver can verify the sender.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use update_available::{Webhook, WebhookFormat, check_crates_io};
+///
+/// let webhook = Webhook::new("https://alerts.example.com/hooks/updates").secret("s3cr3t");
+/// let slack = Webhook::new("https://hooks.slack.com/services/T000/B000/XXXX")
+///     .format(WebhookFormat::Slack);
+/// if let Ok(info) = check_crates_io("serde", "1.0.0") {
+///     if let Err(e) = webhook.notify(&info) {
+///         eprintln!("Failed to send the update event: {e}");
+///     }
+///     let _ = slack.notify(&info);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Webhook {
+    url: String,
+    secret: Option<String>,
+    format: WebhookFormat,
+}
+
+impl Webhook {
+    /// Creates a webhook posting to the given URL.
+    #[must_use]
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_owned(),
+            secret: None,
+            format: WebhookFormat::Json,
+        }
+    }
+
+    /// Signs every request with HMAC-SHA256 using the given secret.
+    #[must_use]
+    pub fn secret(mut self, secret: &str) -> Self {
+        self.secret = Some(secret.to_owned());
+        self
+    }
+
+    /// Sets the payload format.
+    ///
+    /// Defaults to [`WebhookFormat::Json`].
+    #[must_use]
+    pub const fn format(mut self, format: WebhookFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Posts the update information if an update is available.
+    ///
+    /// # Returns
+    ///
+    /// Returns whether the update information was sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the webhook responds with an
+    /// error status.
+    pub fn notify(&self, info: &UpdateInfo) -> anyhow::Result<bool> {
+        if !info.is_update_available {
+            return Ok(false);
+        }
+        self.send(info)?;
+        Ok(true)
+    }
+
+    /// Posts the update information, whether or not an update is available.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the webhook responds with an
+    /// error status.
+    pub fn send(&self, info: &UpdateInfo) -> anyhow::Result<()> {
+        let body = match self.format {
+            WebhookFormat::Json => info.to_json_string()?,
+            WebhookFormat::Slack => info.to_slack_message().to_string(),
+            WebhookFormat::Discord => info.to_discord_message().to_string(),
+        };
+        let mut request = crate::http::post(&self.url).header("Content-Type", "application/json");
+        if let Some(secret) = &self.secret {
+            request = request.header(SIGNATURE_HEADER, signature(secret, body.as_bytes()));
+        }
+        let response = request.send(body)?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            anyhow::bail!("Failed to send data to {}: {}", self.url, response.status());
+        }
+    }
+}
+
+/// Returns the `sha256=<hex digest>` HMAC-SHA256 signature of `body`.
+pub(crate) fn signature(secret: &str, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .unwrap_or_else(|_| unreachable!("HMAC accepts keys of any length"));
+    mac.update(body);
+    mac.finalize()
+        .into_bytes()
+        .iter()
+        .fold(String::from("sha256="), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}